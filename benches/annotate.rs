@@ -0,0 +1,115 @@
+use std::fmt::Write;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use swc_core::{
+    common::{FileName, Globals, Mark, SourceMap, GLOBALS},
+    ecma::{
+        ast::{Module, Pass, Program},
+        parser::{EsSyntax, Parser, StringInput, Syntax},
+        transforms::base::resolver,
+        visit::VisitMutWith,
+    },
+};
+use swc_plugin_component_annotate::{config::PluginConfig, ReactComponentAnnotateVisitor};
+
+/// Generate a module with `count` components covering the shapes the visitor
+/// handles: function, arrow and class components, fragments, conditional
+/// returns, mapped children and returned local variables
+fn generate_source(count: usize) -> String {
+    let mut source = String::from("import React, { Component, Fragment, memo } from 'react';\n");
+
+    for i in 0..count {
+        write!(
+            source,
+            r#"
+function Page{i}({{ items, loading }}) {{
+  if (loading) return <Spinner />;
+  const header = (
+    <header>
+      <Title text="Page {i}" />
+    </header>
+  );
+  return (
+    <Fragment>
+      {{header}}
+      <main className="page">
+        <ul>
+          {{items.map((item) => <Item key={{item.id}} icon={{<Icon name={{item.icon}} />}} />)}}
+        </ul>
+        {{items.length === 0 && <Empty />}}
+      </main>
+    </Fragment>
+  );
+}}
+
+const Card{i} = memo(({{ title, children }}) => (
+  <div className="card">
+    <h2>{{title}}</h2>
+    <Body>{{children}}</Body>
+  </div>
+));
+
+class Panel{i} extends Component {{
+  render() {{
+    return this.props.open ? <section><Content /></section> : <>Closed</>;
+  }}
+}}
+"#
+        )
+        .unwrap();
+    }
+
+    source
+}
+
+fn parse(source: String) -> Module {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), source);
+    let mut parser = Parser::new(
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+        StringInput::from(&*fm),
+        None,
+    );
+
+    parser
+        .parse_module()
+        .expect("generated source should parse")
+}
+
+fn bench_annotate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("annotate");
+    let filename = FileName::Custom("src/components/Generated.jsx".to_string());
+
+    for count in [100, 1_000] {
+        let source = generate_source(count);
+        group.throughput(Throughput::Bytes(source.len() as u64));
+
+        GLOBALS.set(&Globals::new(), || {
+            let mut program = Program::Module(parse(source));
+            let unresolved_mark = Mark::new();
+            resolver(unresolved_mark, Mark::new(), false).process(&mut program);
+
+            group.bench_function(format!("{count}_components"), |b| {
+                b.iter_batched(
+                    || program.clone(),
+                    |mut program| {
+                        let mut visitor =
+                            ReactComponentAnnotateVisitor::new(PluginConfig::default(), &filename)
+                                .with_unresolved_mark(unresolved_mark);
+                        program.visit_mut_with(&mut visitor);
+                        program
+                    },
+                    BatchSize::LargeInput,
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_annotate);
+criterion_main!(benches);
@@ -1,6 +1,14 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+use crate::constants::{
+    DEFAULT_COMPONENT_BASE_CLASSES, DEFAULT_COMPONENT_WRAPPERS, DEFAULT_CREATE_ELEMENT_PRAGMAS,
+    DEFAULT_EMOTION_LABEL_FORMAT, DEFAULT_RENDER_CALLBACKS, DEFAULT_STYLED_IMPORT_SOURCES,
+    DEFAULT_TRANSPARENT_COMPONENTS,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginConfig {
     /// Use React Native attribute names (camelCase) instead of web attributes (kebab-case)
     #[serde(default)]
@@ -29,6 +37,254 @@ pub struct PluginConfig {
     /// Enable rewriting emotion styled components to inject data attributes
     #[serde(default, rename = "rewrite-emotion-styled")]
     pub experimental_rewrite_emotion_styled: bool,
+
+    /// Modules mapped to the exports that act as `styled` in the styled
+    /// rewrite (`"default"` for the default import)
+    #[serde(
+        default = "default_styled_import_sources",
+        rename = "styled-import-sources"
+    )]
+    pub styled_import_sources: BTreeMap<String, Vec<String>>,
+
+    /// Add a `label` to emotion's `styled` and `css` calls, so generated
+    /// class names map back to source (e.g. `css-1x2y-StyledHeader`)
+    #[serde(default, rename = "emotion-label")]
+    pub emotion_label: bool,
+
+    /// Format of emotion labels: `[local]` is the styled component or the
+    /// binding the styles are defined in, `[filename]` the file name without
+    /// extension and `[dirname]` the name of the file's directory
+    #[serde(
+        default = "default_emotion_label_format",
+        rename = "emotion-label-format"
+    )]
+    pub emotion_label_format: String,
+
+    /// Callees whose function argument is treated as the component (e.g.
+    /// `memo`, `forwardRef`)
+    #[serde(default = "default_component_wrappers", rename = "component-wrappers")]
+    pub component_wrappers: Vec<String>,
+
+    /// Name anonymous default-exported components after the file they are
+    /// defined in (e.g. `Home/index.tsx` becomes `Home`)
+    #[serde(default, rename = "name-default-exports-from-file")]
+    pub name_default_exports_from_file: bool,
+
+    /// Which function names are treated as components
+    #[serde(default, rename = "component-detection")]
+    pub component_detection: ComponentDetection,
+
+    /// Superclasses that make a class a component (e.g. `React.Component`).
+    /// Aliased imports from `react` are resolved to these names
+    #[serde(
+        default = "default_component_base_classes",
+        rename = "component-base-classes"
+    )]
+    pub component_base_classes: Vec<String>,
+
+    /// Annotate components with the string literal assigned to their
+    /// `displayName` instead of their local name
+    #[serde(default, rename = "use-display-name")]
+    pub use_display_name: bool,
+
+    /// Also annotate elements created with `React.createElement`, `h` or the
+    /// `react/jsx-runtime` functions, for code where JSX was already compiled
+    #[serde(default, rename = "annotate-create-element")]
+    pub annotate_create_element: bool,
+
+    /// Functions treated as element factories when `annotate-create-element`
+    /// is enabled
+    #[serde(
+        default = "default_create_element_pragmas",
+        rename = "create-element-pragmas"
+    )]
+    pub create_element_pragmas: Vec<String>,
+
+    /// Calls whose callback renders JSX for the calling component (e.g.
+    /// `useMemo`). Render props and function children are always included
+    #[serde(default = "default_render_callbacks", rename = "render-callbacks")]
+    pub render_callbacks: Vec<String>,
+
+    /// Set the component attribute of JSX returned from render callbacks to
+    /// the component owning them
+    #[serde(default, rename = "render-callback-owner")]
+    pub render_callback_owner: bool,
+
+    /// Components that render no DOM node of their own (e.g. `Suspense`,
+    /// `*.Provider`). Like fragments, they pass the component attribute on
+    /// to their children instead of receiving it
+    #[serde(
+        default = "default_transparent_components",
+        rename = "transparent-components"
+    )]
+    pub transparent_components: Vec<String>,
+
+    /// Which element of a component's output gets the component attribute
+    #[serde(default, rename = "component-attr-target")]
+    pub component_attr_target: ComponentAttrTarget,
+
+    /// How host elements, which only get the component attribute, are told
+    /// apart from components
+    #[serde(default, rename = "host-elements")]
+    pub host_elements: HostElements,
+
+    /// Additional element names that are not annotated, on top of the
+    /// built-in HTML, SVG and MathML elements
+    #[serde(default, rename = "extra-ignored-elements")]
+    pub extra_ignored_elements: Vec<String>,
+
+    /// Element names that are always annotated, even if they are host
+    /// elements
+    #[serde(default, rename = "annotate-elements")]
+    pub annotate_elements: Vec<String>,
+
+    /// Additional host primitives in native mode (e.g. Expo or third-party
+    /// components), on top of the built-in React Native primitives
+    #[serde(default, rename = "native-elements")]
+    pub native_elements: Vec<String>,
+}
+
+/// Classification of host elements
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HostElements {
+    /// `"list"`: the built-in HTML, SVG and MathML elements
+    #[default]
+    List,
+    /// `"jsx"`: any element JSX treats as a host element, i.e. lowercase or
+    /// hyphenated names, including custom elements such as `<my-widget>`
+    Jsx,
+}
+
+/// Element receiving the component attribute
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ComponentAttrTarget {
+    /// `"root"`: the root element the component returns
+    #[default]
+    Root,
+    /// `"host"`: the first host (DOM) element of the root, in document
+    /// order, for roots that are custom components which may not pass
+    /// unknown props on to the DOM. Falls back to the root if it has no
+    /// host descendants
+    Host,
+}
+
+/// Policy deciding which named functions define components. Functions that
+/// don't match are treated as helpers that produce JSX for the enclosing
+/// component.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ComponentDetection {
+    /// `"pascal-case"`: names starting with an uppercase letter, following
+    /// the React convention
+    #[default]
+    PascalCase,
+    /// `"any"`: every named function that returns JSX
+    Any,
+    /// Any other string: names matching this regular expression
+    Pattern(String),
+}
+
+impl From<String> for ComponentDetection {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "pascal-case" => Self::PascalCase,
+            "any" => Self::Any,
+            _ => Self::Pattern(value),
+        }
+    }
+}
+
+impl From<ComponentDetection> for String {
+    fn from(value: ComponentDetection) -> Self {
+        match value {
+            ComponentDetection::PascalCase => "pascal-case".to_string(),
+            ComponentDetection::Any => "any".to_string(),
+            ComponentDetection::Pattern(pattern) => pattern,
+        }
+    }
+}
+
+fn default_component_base_classes() -> Vec<String> {
+    DEFAULT_COMPONENT_BASE_CLASSES
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+fn default_create_element_pragmas() -> Vec<String> {
+    DEFAULT_CREATE_ELEMENT_PRAGMAS
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+fn default_emotion_label_format() -> String {
+    DEFAULT_EMOTION_LABEL_FORMAT.to_string()
+}
+
+fn default_styled_import_sources() -> BTreeMap<String, Vec<String>> {
+    DEFAULT_STYLED_IMPORT_SOURCES
+        .iter()
+        .map(|(source, exports)| {
+            let exports = exports.iter().map(|name| name.to_string()).collect();
+            (source.to_string(), exports)
+        })
+        .collect()
+}
+
+fn default_render_callbacks() -> Vec<String> {
+    DEFAULT_RENDER_CALLBACKS
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+fn default_transparent_components() -> Vec<String> {
+    DEFAULT_TRANSPARENT_COMPONENTS
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+fn default_component_wrappers() -> Vec<String> {
+    DEFAULT_COMPONENT_WRAPPERS
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+impl Default for PluginConfig {
+    fn default() -> Self {
+        Self {
+            native: false,
+            ignored_components: Vec::new(),
+            component_attr: None,
+            element_attr: None,
+            source_file_attr: None,
+            source_path_attr: None,
+            experimental_rewrite_emotion_styled: false,
+            styled_import_sources: default_styled_import_sources(),
+            emotion_label: false,
+            emotion_label_format: default_emotion_label_format(),
+            component_wrappers: default_component_wrappers(),
+            name_default_exports_from_file: false,
+            component_detection: ComponentDetection::default(),
+            component_base_classes: default_component_base_classes(),
+            use_display_name: false,
+            annotate_create_element: false,
+            create_element_pragmas: default_create_element_pragmas(),
+            render_callbacks: default_render_callbacks(),
+            render_callback_owner: false,
+            transparent_components: default_transparent_components(),
+            component_attr_target: ComponentAttrTarget::default(),
+            host_elements: HostElements::default(),
+            extra_ignored_elements: Vec::new(),
+            annotate_elements: Vec::new(),
+            native_elements: Vec::new(),
+        }
+    }
 }
 
 impl PluginConfig {
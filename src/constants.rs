@@ -1,6 +1,226 @@
 use rustc_hash::FxHashSet;
 use std::sync::OnceLock;
 
+/// Wrapper calls that return a component built from their function argument
+pub const DEFAULT_COMPONENT_WRAPPERS: &[&str] =
+    &["memo", "forwardRef", "React.memo", "React.forwardRef"];
+
+/// Functions whose calls create elements, as written by hand or emitted by
+/// the classic and automatic JSX transforms
+pub const DEFAULT_CREATE_ELEMENT_PRAGMAS: &[&str] = &[
+    "React.createElement",
+    "createElement",
+    "h",
+    "jsx",
+    "jsxs",
+    "jsxDEV",
+    "_jsx",
+    "_jsxs",
+    "_jsxDEV",
+];
+
+/// Hooks whose callback renders JSX as part of the calling component
+pub const DEFAULT_RENDER_CALLBACKS: &[&str] = &[
+    "useMemo",
+    "useCallback",
+    "React.useMemo",
+    "React.useCallback",
+];
+
+/// Elements that render no DOM node of their own, so their children are
+/// annotated like those of a fragment. `*.Name` matches any member
+/// expression ending in `.Name`
+pub const DEFAULT_TRANSPARENT_COMPONENTS: &[&str] = &[
+    "Suspense",
+    "React.Suspense",
+    "StrictMode",
+    "React.StrictMode",
+    "Profiler",
+    "React.Profiler",
+    "ErrorBoundary",
+    "Router",
+    "BrowserRouter",
+    "HashRouter",
+    "MemoryRouter",
+    "*.Provider",
+    "*.Consumer",
+];
+
+/// Superclasses that make a class with a `render` method a component
+pub const DEFAULT_COMPONENT_BASE_CLASSES: &[&str] = &[
+    "Component",
+    "PureComponent",
+    "React.Component",
+    "React.PureComponent",
+];
+
+/// Default format of emotion labels
+pub const DEFAULT_EMOTION_LABEL_FORMAT: &str = "[local]";
+
+/// Modules whose exports act as `styled`, and the names of those exports
+/// (`default` for the default import)
+pub const DEFAULT_STYLED_IMPORT_SOURCES: &[(&str, &[&str])] = &[
+    ("@emotion/styled", &["default", "styled"]),
+    ("styled-components", &["default", "styled"]),
+    ("@mui/material", &["styled"]),
+    ("@mui/material/styles", &["styled"]),
+    ("@mui/system", &["styled"]),
+    ("@stitches/react", &["styled"]),
+    ("goober", &["styled"]),
+];
+
+/// Statics that are not copied onto the wrapper of a styled component: React's
+/// own (as in hoist-non-react-statics) and those styled libraries use to
+/// recognize their own components
+pub const STYLED_WRAPPER_SKIPPED_STATICS: &[&str] = &[
+    "$$typeof",
+    "render",
+    "type",
+    "compare",
+    "displayName",
+    "defaultProps",
+    "propTypes",
+    "contextType",
+    "contextTypes",
+    "childContextTypes",
+    "getDefaultProps",
+    "getDerivedStateFromProps",
+    "getDerivedStateFromError",
+    "mixins",
+    "styledComponentId",
+    "componentStyle",
+    "foldedComponentIds",
+    "target",
+    "attrs",
+    "shouldForwardProp",
+    "withComponent",
+    "warnTooManyClasses",
+    "toString",
+    "__emotion_real",
+    "__emotion_base",
+    "__emotion_styles",
+    "__emotion_forwardProp",
+];
+
+/// Styled import sources whose `styled` supports `.attrs(...)`, through
+/// which styled host elements (`styled.div`) are annotated
+pub const STYLED_ATTRS_SOURCES: &[&str] = &["styled-components"];
+
+/// Styled import sources whose `styled` takes emotion's options. Styled host
+/// elements are rendered through a wrapper, which `shouldForwardProp` only
+/// passes valid DOM attributes to, as the library does for host elements
+pub const STYLED_FORWARD_PROP_SOURCES: &[&str] = &[
+    "@emotion/styled",
+    "@mui/material",
+    "@mui/material/styles",
+    "@mui/system",
+];
+
+/// Styled import sources that handle `as` and pass the same props to
+/// components as to host elements, so styled host elements can be rendered
+/// through a wrapper as is
+pub const STYLED_WRAPPER_SOURCES: &[&str] = &["@stitches/react"];
+
+/// SVG elements, as written in JSX (e.g. `clipPath`)
+pub const SVG_ELEMENTS: &[&str] = &[
+    "svg",
+    "animate",
+    "animateMotion",
+    "animateTransform",
+    "circle",
+    "clipPath",
+    "defs",
+    "desc",
+    "ellipse",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feImage",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "filter",
+    "foreignObject",
+    "g",
+    "image",
+    "line",
+    "linearGradient",
+    "marker",
+    "mask",
+    "metadata",
+    "mpath",
+    "path",
+    "pattern",
+    "polygon",
+    "polyline",
+    "radialGradient",
+    "rect",
+    "set",
+    "stop",
+    "switch",
+    "symbol",
+    "text",
+    "textPath",
+    "tspan",
+    "use",
+    "view",
+];
+
+/// MathML elements
+pub const MATHML_ELEMENTS: &[&str] = &[
+    "math",
+    "annotation",
+    "annotation-xml",
+    "maction",
+    "menclose",
+    "merror",
+    "mfenced",
+    "mfrac",
+    "mi",
+    "mmultiscripts",
+    "mn",
+    "mo",
+    "mover",
+    "mpadded",
+    "mphantom",
+    "mprescripts",
+    "mroot",
+    "mrow",
+    "ms",
+    "mspace",
+    "msqrt",
+    "mstyle",
+    "msub",
+    "msubsup",
+    "msup",
+    "mtable",
+    "mtd",
+    "mtext",
+    "mtr",
+    "munder",
+    "munderover",
+    "semantics",
+];
+
+/// Host elements that are not annotated: HTML, SVG and MathML
 pub fn default_ignored_elements() -> &'static FxHashSet<&'static str> {
     static SET: OnceLock<FxHashSet<&'static str>> = OnceLock::new();
 
@@ -124,7 +344,44 @@ pub fn default_ignored_elements() -> &'static FxHashSet<&'static str> {
         for element in elements {
             set.insert(element);
         }
+        set.extend(SVG_ELEMENTS);
+        set.extend(MATHML_ELEMENTS);
 
         set
     })
 }
+
+/// React Native primitives that are not annotated in native mode
+pub const REACT_NATIVE_ELEMENTS: &[&str] = &[
+    "ActivityIndicator",
+    "Button",
+    "DrawerLayoutAndroid",
+    "FlatList",
+    "Image",
+    "ImageBackground",
+    "InputAccessoryView",
+    "KeyboardAvoidingView",
+    "Modal",
+    "Pressable",
+    "RefreshControl",
+    "SafeAreaView",
+    "ScrollView",
+    "SectionList",
+    "StatusBar",
+    "Switch",
+    "Text",
+    "TextInput",
+    "TouchableHighlight",
+    "TouchableNativeFeedback",
+    "TouchableOpacity",
+    "TouchableWithoutFeedback",
+    "View",
+    "VirtualizedList",
+];
+
+/// Host elements that are not annotated in native mode
+pub fn default_native_ignored_elements() -> &'static FxHashSet<&'static str> {
+    static SET: OnceLock<FxHashSet<&'static str>> = OnceLock::new();
+
+    SET.get_or_init(|| REACT_NATIVE_ELEMENTS.iter().copied().collect())
+}
@@ -0,0 +1,33 @@
+use regex_lite::Regex;
+
+use crate::config::ComponentDetection;
+
+/// Compiled form of the `component-detection` policy
+pub enum ComponentMatcher {
+    PascalCase,
+    Any,
+    Pattern(Regex),
+}
+
+impl ComponentMatcher {
+    /// An invalid pattern falls back to the default PascalCase convention,
+    /// matching how unparseable plugin configuration is handled
+    pub fn new(detection: &ComponentDetection) -> Self {
+        match detection {
+            ComponentDetection::PascalCase => Self::PascalCase,
+            ComponentDetection::Any => Self::Any,
+            ComponentDetection::Pattern(pattern) => {
+                Regex::new(pattern).map_or(Self::PascalCase, Self::Pattern)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn is_component_name(&self, name: &str) -> bool {
+        match self {
+            Self::PascalCase => name.chars().next().is_some_and(char::is_uppercase),
+            Self::Any => true,
+            Self::Pattern(regex) => regex.is_match(name),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use rustc_hash::FxHashMap;
+use swc_core::ecma::{
+    ast::*,
+    visit::{noop_visit_type, Visit, VisitWith},
+};
+
+use crate::jsx_utils::{get_expr_name, get_expr_root_ident, get_prop_name};
+
+/// Display names by the identifier a component (or the object it's a
+/// member of) is bound to, then by the name it's referred to by
+pub type DisplayNames = FxHashMap<Id, FxHashMap<String, String>>;
+
+/// Find the `displayName` each component of a module sets, keyed by the
+/// binding the component is reached through: `Summary.displayName =
+/// 'Checkout.Summary'`, `Menu.Item.displayName = 'MenuItem'` and `static
+/// displayName = 'Card'`. Keying by binding rather than by name keeps
+/// shadowing bindings from picking up each other's display names. Only
+/// string literals are recorded, since computed names can't be known at
+/// compile time.
+pub fn collect_display_names<N: VisitWith<DisplayNameCollector>>(node: &N) -> DisplayNames {
+    let mut collector = DisplayNameCollector::default();
+    node.visit_with(&mut collector);
+    collector.display_names
+}
+
+/// Value of a string literal, or of a template literal without expressions
+fn get_string_value(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => str.value.as_str(),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+            .quasis
+            .first()
+            .and_then(|quasi| quasi.cooked.as_ref())
+            .and_then(|cooked| cooked.as_str()),
+        Expr::Paren(paren_expr) => get_string_value(&paren_expr.expr),
+        _ => None,
+    }
+}
+
+/// `static displayName = '...'` declared in a class body
+fn get_static_display_name(class: &Class) -> Option<&str> {
+    class.body.iter().find_map(|member| match member {
+        ClassMember::ClassProp(class_prop)
+            if class_prop.is_static && get_prop_name(&class_prop.key) == Some("displayName") =>
+        {
+            class_prop.value.as_deref().and_then(get_string_value)
+        }
+        _ => None,
+    })
+}
+
+#[derive(Default)]
+pub struct DisplayNameCollector {
+    display_names: DisplayNames,
+}
+
+impl DisplayNameCollector {
+    fn record(&mut self, binding: &Ident, component_name: String, display_name: &str) {
+        self.display_names
+            .entry(binding.to_id())
+            .or_default()
+            .insert(component_name, display_name.to_string());
+    }
+}
+
+impl Visit for DisplayNameCollector {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+        if let AssignTarget::Simple(SimpleAssignTarget::Member(member_expr)) = &assign_expr.left {
+            let is_display_name = matches!(&member_expr.prop,
+                MemberProp::Ident(prop) if prop.sym.as_ref() == "displayName");
+
+            if assign_expr.op == AssignOp::Assign && is_display_name {
+                if let (Some(binding), Some(component_name), Some(display_name)) = (
+                    get_expr_root_ident(&member_expr.obj),
+                    get_expr_name(&member_expr.obj),
+                    get_string_value(&assign_expr.right),
+                ) {
+                    self.record(binding, component_name.into_owned(), display_name);
+                }
+            }
+        }
+
+        assign_expr.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        if let Some(display_name) = get_static_display_name(&class_decl.class) {
+            self.record(
+                &class_decl.ident,
+                class_decl.ident.sym.to_string(),
+                display_name,
+            );
+        }
+
+        class_decl.visit_children_with(self);
+    }
+
+    fn visit_class_expr(&mut self, class_expr: &ClassExpr) {
+        if let (Some(ident), Some(display_name)) = (
+            &class_expr.ident,
+            get_static_display_name(&class_expr.class),
+        ) {
+            self.record(ident, ident.sym.to_string(), display_name);
+        }
+
+        class_expr.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+        // const Card = class extends Component { static displayName = '...' }
+        if let (Pat::Ident(binding), Some(Expr::Class(class_expr))) =
+            (&var_declarator.name, var_declarator.init.as_deref())
+        {
+            if let Some(display_name) = get_static_display_name(&class_expr.class) {
+                self.record(&binding.id, binding.id.sym.to_string(), display_name);
+            }
+        }
+
+        var_declarator.visit_children_with(self);
+    }
+}
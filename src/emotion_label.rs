@@ -0,0 +1,52 @@
+use swc_core::common::FileName;
+
+use crate::path_utils::{extract_dirname, extract_file_stem};
+
+/// Compiled form of the `emotion-label-format` option, with the file parts
+/// of the format already filled in
+pub struct EmotionLabel {
+    format: String,
+}
+
+impl EmotionLabel {
+    pub fn new(format: &str, filename: &FileName) -> Self {
+        let filename_part = extract_file_stem(filename).unwrap_or_default();
+        let dirname_part = extract_dirname(filename).unwrap_or_default();
+
+        Self {
+            format: format
+                .replace("[filename]", &sanitize_label_part(&filename_part))
+                .replace("[dirname]", &sanitize_label_part(&dirname_part)),
+        }
+    }
+
+    /// Label for styles defined in `local` (a styled component, the binding
+    /// of a `css` call or its enclosing component). `None` if the format
+    /// needs a local name and there is none.
+    pub fn label(&self, local: Option<&str>) -> Option<String> {
+        if !self.format.contains("[local]") {
+            return Some(self.format.clone());
+        }
+
+        Some(self.format.replace("[local]", &sanitize_label_part(local?)))
+    }
+}
+
+/// Replace the characters a class name can't contain, as emotion's own
+/// label sanitization does: `Layout.Header` becomes `Layout-Header`
+fn sanitize_label_part(part: &str) -> String {
+    let mut sanitized = String::with_capacity(part.len());
+    let mut in_invalid_run = false;
+
+    for c in part.trim().chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+            sanitized.push(c);
+            in_invalid_run = false;
+        } else if !in_invalid_run {
+            sanitized.push('-');
+            in_invalid_run = true;
+        }
+    }
+
+    sanitized
+}
@@ -1,20 +1,41 @@
 use std::borrow::Cow;
 use swc_core::ecma::ast::*;
 
-/// Check if a JSX element is a React Fragment
+/// Check if JSX treats an element name as a host element rather than a
+/// component reference: lowercase or hyphenated names (`div`, `my-widget`),
+/// but not member expressions
 #[inline]
-pub fn is_react_fragment(element: &JSXElementName) -> bool {
-    match element {
-        JSXElementName::Ident(ident) => ident.sym.as_ref() == "Fragment",
-        JSXElementName::JSXMemberExpr(member_expr) => matches!(
-            &member_expr.obj,
-            JSXObject::Ident(obj)
-                if obj.sym.as_ref() == "React" && member_expr.prop.sym.as_ref() == "Fragment"
-        ),
-        JSXElementName::JSXNamespacedName(_) => false,
-        #[cfg(swc_ast_unknown)]
-        _ => panic!("unknown jsx element name"),
+pub fn is_jsx_host_element_name(element_name: &str) -> bool {
+    !element_name.contains('.')
+        && (element_name.starts_with(|c: char| c.is_ascii_lowercase())
+            || element_name.contains('-'))
+}
+
+/// Find the first element among `children` and their descendants matching
+/// `is_match`, searching depth-first in document order. Expression
+/// containers are not searched.
+pub fn find_first_element_mut<'a>(
+    children: &'a mut [JSXElementChild],
+    is_match: &impl Fn(&JSXElementName) -> bool,
+) -> Option<&'a mut JSXOpeningElement> {
+    for child in children {
+        let descendants = match child {
+            JSXElementChild::JSXElement(jsx_element) => {
+                if is_match(&jsx_element.opening.name) {
+                    return Some(&mut jsx_element.opening);
+                }
+                &mut jsx_element.children
+            }
+            JSXElementChild::JSXFragment(jsx_fragment) => &mut jsx_fragment.children,
+            _ => continue,
+        };
+
+        if let Some(found) = find_first_element_mut(descendants, is_match) {
+            return Some(found);
+        }
     }
+
+    None
 }
 
 /// Extract the element name from a JSX element
@@ -33,6 +54,27 @@ pub fn get_element_name(element: &JSXElementName) -> Cow<str> {
     }
 }
 
+/// Identifier a JSX element name is rooted at: `Card` for `<Card>` and
+/// `Menu` for `<Menu.Item>`
+pub fn get_element_root_ident(element: &JSXElementName) -> Option<&Ident> {
+    fn member_root_ident(member_expr: &JSXMemberExpr) -> &Ident {
+        match &member_expr.obj {
+            JSXObject::Ident(ident) => ident,
+            JSXObject::JSXMemberExpr(nested_member) => member_root_ident(nested_member),
+            #[cfg(swc_ast_unknown)]
+            _ => panic!("unknown jsx object"),
+        }
+    }
+
+    match element {
+        JSXElementName::Ident(ident) => Some(ident),
+        JSXElementName::JSXMemberExpr(member_expr) => Some(member_root_ident(member_expr)),
+        JSXElementName::JSXNamespacedName(_) => None,
+        #[cfg(swc_ast_unknown)]
+        _ => panic!("unknown jsx element name"),
+    }
+}
+
 /// Recursively build the name for member expressions (e.g., "Components.UI.Button")
 fn get_member_expression_name(member_expr: &JSXMemberExpr) -> String {
     fn member_expression_name_len(member_expr: &JSXMemberExpr) -> usize {
@@ -65,6 +107,89 @@ fn get_member_expression_name(member_expr: &JSXMemberExpr) -> String {
     output
 }
 
+/// Build the dotted name of an identifier or member expression (e.g.
+/// "React.memo"), returning `None` for any other expression
+pub fn get_expr_name(expr: &Expr) -> Option<Cow<str>> {
+    match expr {
+        Expr::Ident(ident) => Some(Cow::Borrowed(ident.sym.as_ref())),
+        Expr::Member(member_expr) => get_member_name(member_expr).map(Cow::Owned),
+        Expr::Paren(paren_expr) => get_expr_name(&paren_expr.expr),
+        #[cfg(swc_ast_unknown)]
+        Expr::Unknown(..) => panic!("unknown expr"),
+        _ => None,
+    }
+}
+
+/// Build the dotted name of a member expression (e.g. "Foo.Item"),
+/// returning `None` for computed or private properties
+pub fn get_member_name(member_expr: &MemberExpr) -> Option<String> {
+    let MemberProp::Ident(prop) = &member_expr.prop else {
+        return None;
+    };
+    let obj_name = get_expr_name(&member_expr.obj)?;
+    Some(format!("{}.{}", obj_name, prop.sym))
+}
+
+/// Get the name of an identifier or string object key
+#[inline]
+pub fn get_prop_name(key: &PropName) -> Option<&str> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.as_ref()),
+        PropName::Str(str) => str.value.as_str(),
+        _ => None,
+    }
+}
+
+/// Find the identifier a dotted name built by `get_expr_name` starts with
+/// (e.g. `React` in `React.Component`)
+pub fn get_expr_root_ident(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Member(member_expr) => get_expr_root_ident(&member_expr.obj),
+        Expr::Paren(paren_expr) => get_expr_root_ident(&paren_expr.expr),
+        #[cfg(swc_ast_unknown)]
+        Expr::Unknown(..) => panic!("unknown expr"),
+        _ => None,
+    }
+}
+
+/// Check if a class member key is `render`, written as `render`, `'render'`
+/// or `['render']`
+#[inline]
+pub fn is_render_key(key: &PropName) -> bool {
+    match key {
+        PropName::Ident(ident) => ident.sym.as_ref() == "render",
+        PropName::Str(str) => str.value == "render",
+        PropName::Computed(computed) => {
+            matches!(computed.expr.as_ref(), Expr::Lit(Lit::Str(str)) if str.value == "render")
+        }
+        _ => false,
+    }
+}
+
+/// Check if an expression is `null` or `undefined`
+#[inline]
+pub fn is_nullish(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(Lit::Null(_)) => true,
+        Expr::Ident(ident) => ident.sym.as_ref() == "undefined",
+        _ => false,
+    }
+}
+
+/// Check if an object literal already has a property with the given name
+#[inline]
+pub fn has_prop(object: &ObjectLit, prop_name: &str) -> bool {
+    object.props.iter().any(|prop| match prop {
+        PropOrSpread::Prop(prop) => match prop.as_ref() {
+            Prop::KeyValue(key_value) => get_prop_name(&key_value.key) == Some(prop_name),
+            Prop::Shorthand(ident) => ident.sym.as_ref() == prop_name,
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
 /// Check if a JSX element already has an attribute with the given name
 #[inline]
 pub fn has_attribute(element: &JSXOpeningElement, attr_name: &str) -> bool {
@@ -90,16 +215,12 @@ pub fn create_jsx_attr(name: &str, value: &str) -> JSXAttrOrSpread {
 }
 
 #[inline]
-pub fn create_jsx_attr_with_ident(name: &IdentName, value: &str) -> JSXAttrOrSpread {
-    JSXAttrOrSpread::JSXAttr(JSXAttr {
+pub fn create_str(value: &str) -> Str {
+    Str {
         span: Default::default(),
-        name: JSXAttrName::Ident(name.clone()),
-        value: Some(JSXAttrValue::Str(Str {
-            span: Default::default(),
-            value: value.into(),
-            raw: None,
-        })),
-    })
+        value: value.into(),
+        raw: None,
+    }
 }
 
 #[inline]
@@ -110,3 +231,78 @@ pub fn create_jsx_attr_with_ident_and_str(name: &IdentName, value: &Str) -> JSXA
         value: Some(JSXAttrValue::Str(value.clone())),
     })
 }
+
+/// Create an object property, using an identifier key if the name is a
+/// valid identifier (`dataComponent: "App"`) and a string key otherwise
+/// (`"data-component": "App"`)
+pub fn create_prop_with_str(name: &str, value: Str) -> PropOrSpread {
+    let key = if Ident::verify_symbol(name).is_ok() {
+        PropName::Ident(IdentName::new(name.into(), Default::default()))
+    } else {
+        PropName::Str(create_str(name))
+    };
+
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key,
+        value: Box::new(Expr::Lit(Lit::Str(value))),
+    })))
+}
+
+#[inline]
+pub fn create_ident(name: &str) -> Expr {
+    Expr::Ident(Ident::new_no_ctxt(name.into(), Default::default()))
+}
+
+#[inline]
+pub fn create_member(obj: Expr, prop: &str) -> Expr {
+    Expr::Member(MemberExpr {
+        span: Default::default(),
+        obj: Box::new(obj),
+        prop: MemberProp::Ident(IdentName::new(prop.into(), Default::default())),
+    })
+}
+
+#[inline]
+pub fn create_call(callee: Expr, args: Vec<Expr>) -> Expr {
+    Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(callee)),
+        args: args.into_iter().map(Into::into).collect(),
+        ..Default::default()
+    })
+}
+
+#[inline]
+pub fn create_object(props: Vec<PropOrSpread>) -> Expr {
+    Expr::Object(ObjectLit {
+        span: Default::default(),
+        props,
+    })
+}
+
+/// Convert a component reference (`Button`, `UI.Button`) to the name of a
+/// JSX element rendering it
+pub fn create_jsx_element_name(expr: &Expr) -> Option<JSXElementName> {
+    fn create_jsx_object(expr: &Expr) -> Option<JSXObject> {
+        match create_jsx_element_name(expr)? {
+            JSXElementName::Ident(ident) => Some(JSXObject::Ident(ident)),
+            JSXElementName::JSXMemberExpr(member_expr) => {
+                Some(JSXObject::JSXMemberExpr(Box::new(member_expr)))
+            }
+            _ => None,
+        }
+    }
+
+    match expr {
+        Expr::Ident(ident) => Some(JSXElementName::Ident(ident.clone())),
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => Some(JSXElementName::JSXMemberExpr(JSXMemberExpr {
+            span: Default::default(),
+            obj: create_jsx_object(obj)?,
+            prop: prop.clone(),
+        })),
+        _ => None,
+    }
+}
@@ -1,14 +1,25 @@
 pub mod config;
 mod constants;
+mod detection;
+mod display_names;
+mod emotion_label;
 mod jsx_utils;
 pub mod path_utils;
+mod root_bindings;
+mod scope;
 
-use config::PluginConfig;
+use config::{ComponentAttrTarget, HostElements, PluginConfig};
+use detection::ComponentMatcher;
+use display_names::{collect_display_names, DisplayNames};
+use emotion_label::EmotionLabel;
 use jsx_utils::*;
-use path_utils::{extract_absolute_path, extract_filename};
-use rustc_hash::FxHashSet;
+use path_utils::{extract_absolute_path, extract_component_name, extract_filename};
+use root_bindings::collect_root_bindings;
+use rustc_hash::{FxHashMap, FxHashSet};
+use scope::ComponentScope;
+use std::borrow::Cow;
 use swc_core::{
-    common::{FileName, DUMMY_SP},
+    common::{util::take::Take, FileName, Mark, SyntaxContext, DUMMY_SP},
     ecma::{
         ast::*,
         visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
@@ -23,15 +34,69 @@ pub struct ReactComponentAnnotateVisitor {
     config: PluginConfig,
     source_file_name: Option<Str>,
     source_file_path: Option<Str>,
-    current_component_name: Option<String>,
+    /// One scope per enclosing function or class, innermost last. The first
+    /// entry is the module scope and is never popped.
+    scopes: Vec<ComponentScope>,
     ignored_elements: &'static FxHashSet<&'static str>,
+    extra_ignored_elements_set: FxHashSet<String>,
+    annotated_elements_set: FxHashSet<String>,
     ignored_components_set: FxHashSet<String>,
+    component_wrappers_set: FxHashSet<String>,
+    component_matcher: ComponentMatcher,
+    component_base_classes_set: FxHashSet<String>,
+    create_element_pragmas_set: FxHashSet<String>,
+    render_callbacks_set: FxHashSet<String>,
+    transparent_components_set: FxHashSet<String>,
+    /// Member suffixes (`.Provider`) from `*.Provider` transparent components
+    transparent_component_suffixes: Vec<String>,
+    /// Bindings imported from `react` (or its JSX runtimes), mapped to the
+    /// name they refer to:
+    /// `Component` for `import { Component as Base }`, and `React` for
+    /// default and namespace imports
+    react_imports: FxHashMap<Id, String>,
+    /// Bindings of value (not type-only) imports from `react` itself, the
+    /// ones styled wrappers can call `forwardRef` through
+    react_value_imports: FxHashSet<Id>,
+    /// Bindings imported from `react-native` (in native mode), mapped to the
+    /// name they were imported as
+    react_native_imports: FxHashMap<Id, String>,
+    /// Local bindings of every import of the module, from any source
+    imported_bindings: FxHashSet<Id>,
+    /// Context of unresolved (global) identifiers, such as a UMD `React`
+    unresolved_ctxt: SyntaxContext,
+    /// `displayName` of each component in the module, keyed by binding.
+    /// Only collected if `use-display-name` is enabled
+    display_names: DisplayNames,
     component_attr_ident: IdentName,
     element_attr_ident: IdentName,
     source_file_attr_ident: IdentName,
     source_path_attr_ident: Option<IdentName>,
-    /// Track the local identifier name for `styled` from @emotion/styled
-    styled_import: Option<String>,
+    /// Component name derived from the file, used for `module.exports` and
+    /// (if enabled) anonymous default exports
+    file_component_name: Option<String>,
+    /// Local bindings of `styled` imported from one of the styled import
+    /// sources
+    styled_imports: FxHashSet<Id>,
+    /// Local binding of `forwardRef` imported for styled wrappers, when the
+    /// file doesn't import it already
+    forward_ref_import: Option<Ident>,
+    /// Local binding of the helper copying statics onto styled wrappers,
+    /// declared once per module when needed
+    hoist_statics_helper: Option<Ident>,
+    /// Labels for emotion styles (if enabled)
+    emotion_label: Option<EmotionLabel>,
+    /// Local bindings of `styled` from libraries whose host elements can be
+    /// annotated, and how
+    styled_host_imports: FxHashMap<Id, StyledHost>,
+    /// Local binding of `isPropValid` imported for styled host wrappers
+    is_prop_valid_import: Option<Ident>,
+    /// Local bindings of `styled` imported from @emotion/styled
+    emotion_styled_imports: FxHashSet<Id>,
+    /// Local bindings of `css` imported from @emotion/react
+    emotion_css_imports: FxHashSet<Id>,
+    /// Name of the innermost binding being visited, the `[local]` part of
+    /// emotion labels
+    label_local: Option<String>,
 }
 
 impl ReactComponentAnnotateVisitor {
@@ -47,9 +112,45 @@ impl ReactComponentAnnotateVisitor {
             raw: None,
         });
 
+        let file_component_name = extract_component_name(filename);
+        let emotion_label = config
+            .emotion_label
+            .then(|| EmotionLabel::new(&config.emotion_label_format, filename));
+
         // Pre-compute ignored components set for O(1) lookups
         let ignored_components_set: FxHashSet<String> =
             config.ignored_components.iter().cloned().collect();
+        let ignored_elements = if config.native {
+            constants::default_native_ignored_elements()
+        } else {
+            constants::default_ignored_elements()
+        };
+        let mut extra_ignored_elements_set: FxHashSet<String> =
+            config.extra_ignored_elements.iter().cloned().collect();
+        if config.native {
+            extra_ignored_elements_set.extend(config.native_elements.iter().cloned());
+        }
+        let annotated_elements_set: FxHashSet<String> =
+            config.annotate_elements.iter().cloned().collect();
+        let component_wrappers_set: FxHashSet<String> =
+            config.component_wrappers.iter().cloned().collect();
+        let component_matcher = ComponentMatcher::new(&config.component_detection);
+        let component_base_classes_set: FxHashSet<String> =
+            config.component_base_classes.iter().cloned().collect();
+        let create_element_pragmas_set: FxHashSet<String> =
+            config.create_element_pragmas.iter().cloned().collect();
+        let render_callbacks_set: FxHashSet<String> =
+            config.render_callbacks.iter().cloned().collect();
+        let (transparent_component_patterns, transparent_components): (Vec<_>, Vec<_>) = config
+            .transparent_components
+            .iter()
+            .partition(|name| name.starts_with("*."));
+        let transparent_components_set: FxHashSet<String> =
+            transparent_components.into_iter().cloned().collect();
+        let transparent_component_suffixes: Vec<String> = transparent_component_patterns
+            .into_iter()
+            .map(|pattern| pattern[1..].to_string())
+            .collect();
         let component_attr_ident = IdentName::new(config.component_attr_name().into(), DUMMY_SP);
         let element_attr_ident = IdentName::new(config.element_attr_name().into(), DUMMY_SP);
         let source_file_attr_ident =
@@ -63,33 +164,376 @@ impl ReactComponentAnnotateVisitor {
             component_attr_ident,
             config,
             element_attr_ident,
-            ignored_elements: constants::default_ignored_elements(),
+            ignored_elements,
+            extra_ignored_elements_set,
+            annotated_elements_set,
             ignored_components_set,
+            component_wrappers_set,
+            component_matcher,
+            component_base_classes_set,
+            create_element_pragmas_set,
+            render_callbacks_set,
+            transparent_components_set,
+            transparent_component_suffixes,
+            react_imports: FxHashMap::default(),
+            react_value_imports: FxHashSet::default(),
+            react_native_imports: FxHashMap::default(),
+            imported_bindings: FxHashSet::default(),
+            unresolved_ctxt: SyntaxContext::empty(),
+            display_names: FxHashMap::default(),
+            file_component_name,
             source_file_name,
             source_file_attr_ident,
             source_file_path,
             source_path_attr_ident,
-            current_component_name: None,
-            styled_import: None,
+            scopes: vec![ComponentScope::default()],
+            styled_imports: FxHashSet::default(),
+            forward_ref_import: None,
+            hoist_statics_helper: None,
+            emotion_label,
+            styled_host_imports: FxHashMap::default(),
+            is_prop_valid_import: None,
+            emotion_styled_imports: FxHashSet::default(),
+            emotion_css_imports: FxHashSet::default(),
+            label_local: None,
         }
     }
 
+    /// Set the mark the resolver gave unresolved identifiers, so a global
+    /// `React` (UMD builds, webpack's ProvidePlugin) is recognized as React.
+    /// Without it, only identifiers without any context are.
+    pub fn with_unresolved_mark(mut self, unresolved_mark: Mark) -> Self {
+        self.unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
+        self
+    }
+
     #[inline]
     pub fn should_ignore_component(&self, component_name: &str) -> bool {
         self.ignored_components_set.contains(component_name)
     }
 
+    /// Check if a callee is a configured component wrapper, including aliased
+    /// imports from react (`R.memo` from `import * as R`, `m` from
+    /// `import { memo as m }`)
+    #[inline]
+    fn is_component_wrapper(&self, callee: &Callee) -> bool {
+        self.is_callee_in(callee, &self.component_wrappers_set)
+    }
+
+    /// Dotted name of an identifier or member expression, with bindings
+    /// imported from `react` resolved to the name they were imported as
+    /// (e.g. `Base` from `import { Component as Base }` becomes `Component`,
+    /// `R.Component` from `import * as R` becomes `React.Component`)
+    fn resolve_react_name<'a>(&self, expr: &'a Expr) -> Option<Cow<'a, str>> {
+        self.react_import_name(expr)
+            .map(Cow::Owned)
+            .or_else(|| get_expr_name(expr))
+    }
+
+    /// Like `resolve_react_name`, but only for expressions referring to a
+    /// binding imported from `react`
+    fn react_import_name(&self, expr: &Expr) -> Option<String> {
+        let root = get_expr_root_ident(expr)?;
+        let imported = self.react_imports.get(&root.to_id())?;
+        let name = get_expr_name(expr)?;
+
+        Some(format!("{}{}", imported, &name[root.sym.len()..]))
+    }
+
+    /// Check if an identifier refers to React itself: a default or namespace
+    /// import of `react`, or a global `React`
+    fn is_react_binding(&self, ident: &Ident) -> bool {
+        match self.react_imports.get(&ident.to_id()) {
+            Some(imported) => imported == "React",
+            None => ident.sym == "React" && ident.ctxt == self.unresolved_ctxt,
+        }
+    }
+
+    /// Check if a JSX element is React's Fragment, through the binding it
+    /// was imported as (`<Fragment>`, `<F>` from `import { Fragment as F }`,
+    /// `<React.Fragment>`, `<R.Fragment>` from `import * as R`, or a global
+    /// `React`). A local component named `Fragment`, or one shadowing the
+    /// import, is not.
+    fn is_react_fragment(&self, element_name: &JSXElementName) -> bool {
+        match element_name {
+            JSXElementName::Ident(ident) => self
+                .react_imports
+                .get(&ident.to_id())
+                .is_some_and(|imported| imported == "Fragment"),
+            JSXElementName::JSXMemberExpr(member_expr) => {
+                member_expr.prop.sym.as_ref() == "Fragment"
+                    && matches!(&member_expr.obj,
+                        JSXObject::Ident(obj) if self.is_react_binding(obj))
+            }
+            JSXElementName::JSXNamespacedName(_) => false,
+            #[cfg(swc_ast_unknown)]
+            _ => panic!("unknown jsx element name"),
+        }
+    }
+
+    /// Record the bindings of `const React = require('react')` and
+    /// `const { Fragment } = require('react')` like the matching imports,
+    /// as well as those destructured from React itself:
+    /// `const { Fragment } = React`
+    fn track_react_require(&mut self, var_declarator: &VarDeclarator) {
+        let is_react_source = match var_declarator.init.as_deref() {
+            Some(Expr::Call(call_expr)) => {
+                matches!(call_expr.callee.as_expr().map(|callee| &**callee),
+                    Some(Expr::Ident(callee)) if callee.sym.as_ref() == "require")
+                    && matches!(call_expr.args.as_slice(),
+                        [ExprOrSpread { spread: None, expr }]
+                            if matches!(&**expr, Expr::Lit(Lit::Str(src)) if src.value == "react"))
+            }
+            Some(Expr::Ident(ident)) => self.is_react_binding(ident),
+            _ => false,
+        };
+        if !is_react_source {
+            return;
+        }
+
+        match &var_declarator.name {
+            Pat::Ident(binding) => {
+                self.react_imports
+                    .insert(binding.id.to_id(), "React".to_string());
+            }
+            Pat::Object(object_pat) => {
+                for prop in &object_pat.props {
+                    match prop {
+                        // const { Fragment: F } = require('react')
+                        ObjectPatProp::KeyValue(KeyValuePatProp { key, value, .. }) => {
+                            if let (Some(imported), Pat::Ident(binding)) =
+                                (get_prop_name(key), value.as_ref())
+                            {
+                                self.react_imports
+                                    .insert(binding.id.to_id(), imported.to_string());
+                            }
+                        }
+                        // const { Fragment } = require('react')
+                        ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
+                            self.react_imports
+                                .insert(key.id.to_id(), key.id.sym.to_string());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Check if a class extends one of the configured component base classes
+    fn is_component_class(&self, class: &Class) -> bool {
+        class
+            .super_class
+            .as_deref()
+            .and_then(|super_class| self.resolve_react_name(super_class))
+            .is_some_and(|name| self.component_base_classes_set.contains(name.as_ref()))
+    }
+
+    #[inline]
+    fn scope(&self) -> &ComponentScope {
+        self.scopes.last().expect("module scope is never popped")
+    }
+
+    #[inline]
+    fn scope_mut(&mut self) -> &mut ComponentScope {
+        self.scopes
+            .last_mut()
+            .expect("module scope is never popped")
+    }
+
+    /// The component attribute value for JSX at the current position. Roots
+    /// of render callbacks only carry their owner if enabled
+    #[inline]
+    fn current_component_name(&self) -> Option<&str> {
+        let scope = self.scope();
+        if scope.is_render_callback && !self.config.render_callback_owner {
+            return None;
+        }
+        scope.root_component_name()
+    }
+
+    /// Nearest component enclosing the current position
+    fn enclosing_component_name(&self) -> Option<String> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.component_name.clone())
+    }
+
+    /// Component a function named `name` renders for: itself if the name
+    /// passes the detection policy, otherwise the nearest enclosing
+    /// component, so JSX produced by helpers such as `renderRow` is
+    /// attributed to the component calling them. `binding` is the
+    /// identifier the function is reached through, used to find its
+    /// `displayName`.
+    fn component_name_for(&self, binding: Option<&Id>, name: &str) -> Option<String> {
+        // Qualified names such as `parts.Header` are matched on their last
+        // segment
+        let local_name = name.rsplit('.').next().unwrap_or(name);
+        if self.component_matcher.is_component_name(local_name) {
+            return Some(self.annotated_name(binding, name.to_string()));
+        }
+
+        self.enclosing_component_name()
+    }
+
+    /// `displayName` of the component named `name` and bound to `binding`,
+    /// if one was found
+    #[inline]
+    fn display_name(&self, binding: Option<&Id>, name: &str) -> Option<&str> {
+        if self.display_names.is_empty() {
+            return None;
+        }
+
+        self.display_names
+            .get(binding?)?
+            .get(name)
+            .map(String::as_str)
+    }
+
+    /// Name to annotate a component bound to `binding` with: its
+    /// `displayName`, if one was found, otherwise its local name
+    fn annotated_name(&self, binding: Option<&Id>, component_name: String) -> String {
+        match self.display_name(binding, &component_name) {
+            Some(display_name) => display_name.to_string(),
+            None => component_name,
+        }
+    }
+
+    /// Run `f` inside a new scope, restoring the enclosing one afterwards
+    fn with_scope(&mut self, scope: ComponentScope, f: impl FnOnce(&mut Self)) {
+        self.scopes.push(scope);
+        f(self);
+        self.scopes.pop();
+    }
+
+    /// Run `f` with JSX in (or out of) a root position of the current scope
+    fn with_root(&mut self, in_root: bool, f: impl FnOnce(&mut Self)) {
+        let prev_in_root = std::mem::replace(&mut self.scope_mut().in_root, in_root);
+        f(self);
+        self.scope_mut().in_root = prev_in_root;
+    }
+
+    /// Check if an element passes its role on to its children instead of
+    /// being annotated: a fragment or one of the transparent components
+    fn is_transparent_element(&self, element_name: &JSXElementName) -> bool {
+        self.is_react_fragment(element_name)
+            || self.is_transparent_component(&get_element_name(element_name))
+    }
+
+    fn is_transparent_component(&self, element_name: &str) -> bool {
+        self.transparent_components_set.contains(element_name)
+            || self
+                .transparent_component_suffixes
+                .iter()
+                .any(|suffix| element_name.ends_with(suffix.as_str()))
+    }
+
+    /// Check if an element (rooted at `element_binding`) is a host element
+    /// that only gets the component attribute, not the element attribute
     #[inline]
-    fn should_ignore_element(&self, element_name: &str) -> bool {
-        self.ignored_elements.contains(element_name)
+    fn should_ignore_element(&self, element_name: &str, element_binding: Option<&Id>) -> bool {
+        if self.annotated_elements_set.contains(element_name) {
+            return false;
+        }
+        if self.extra_ignored_elements_set.contains(element_name) {
+            return true;
+        }
+
+        let is_listed = if self.config.native {
+            self.is_native_primitive(element_name, element_binding)
+        } else {
+            self.ignored_elements.contains(element_name)
+        };
+        match self.config.host_elements {
+            HostElements::List => is_listed,
+            HostElements::Jsx => {
+                is_jsx_host_element_name(element_name) || (self.config.native && is_listed)
+            }
+        }
+    }
+
+    /// Check if an element is a React Native primitive, through the binding
+    /// it was imported from `react-native` as. Names such as `Button` or
+    /// `Modal` are common in design systems, so a local or otherwise
+    /// imported `Button` is not.
+    fn is_native_primitive(&self, element_name: &str, element_binding: Option<&Id>) -> bool {
+        let Some(binding) = element_binding else {
+            return false;
+        };
+        let Some(imported) = self.react_native_imports.get(binding) else {
+            return false;
+        };
+
+        // <Alias.Member> is matched as <Imported.Member>
+        match element_name.strip_prefix(binding.0.as_ref()) {
+            Some("") => self.ignored_elements.contains(imported.as_str()),
+            Some(member) => self
+                .ignored_elements
+                .contains(format!("{}{}", imported, member).as_str()),
+            None => false,
+        }
+    }
+
+    /// Check if an element renders a DOM node: a known HTML element or any
+    /// other lowercase name (such as a custom element)
+    fn is_host_element(&self, element_name: &JSXElementName) -> bool {
+        match element_name {
+            JSXElementName::Ident(ident) => {
+                self.should_ignore_element(&ident.sym, Some(&ident.to_id()))
+                    || is_jsx_host_element_name(&ident.sym)
+            }
+            _ => false,
+        }
+    }
+
+    /// In `host` mode, the component attribute of a root that is a custom
+    /// component goes to its first host descendant instead, if it has one
+    fn should_push_component_attr_down(&self, element: &mut JSXElement) -> bool {
+        self.config.component_attr_target == ComponentAttrTarget::Host
+            && self.current_component_name().is_some()
+            && !self.is_host_element(&element.opening.name)
+            && find_first_element_mut(&mut element.children, &|name| self.is_host_element(name))
+                .is_some()
+    }
+
+    /// Add the component attribute to the first host descendant of a root
+    fn push_component_attr_down(&self, element: &mut JSXElement, component_name: &str) {
+        if self.should_ignore_component(component_name) {
+            return;
+        }
+
+        let component_attr_name = self.config.component_attr_name();
+        if let Some(host_element) =
+            find_first_element_mut(&mut element.children, &|name| self.is_host_element(name))
+        {
+            if !has_attribute(host_element, component_attr_name) {
+                host_element.attrs.push(create_jsx_attr_with_ident_and_str(
+                    &self.component_attr_ident,
+                    &create_str(component_name),
+                ));
+            }
+        }
     }
 
     fn process_jsx_element(&mut self, element: &mut JSXElement) {
-        // Check if this is a named fragment (Fragment, React.Fragment)
-        let is_fragment = is_react_fragment(&element.opening.name);
+        // Check if this is a named fragment (Fragment, React.Fragment) or
+        // another transparent element such as <Suspense>
+        let is_fragment = self.is_transparent_element(&element.opening.name);
+
+        // JSX passed as a prop (icon={<Icon />}, renderItem={() => <Row />})
+        // is never a component root
+        self.with_root(false, |this| element.opening.attrs.visit_mut_with(this));
+
+        let host_component_name = if !is_fragment && self.should_push_component_attr_down(element) {
+            self.current_component_name().map(str::to_string)
+        } else {
+            None
+        };
 
         if !is_fragment {
-            self.add_attributes_to_element(&mut element.opening);
+            self.add_attributes_to_element(&mut element.opening, host_component_name.is_none());
         }
 
         // Process children - fragments are transparent containers
@@ -101,20 +545,32 @@ impl ReactComponentAnnotateVisitor {
                         jsx_element.visit_mut_with(self);
                     } else {
                         // Non-fragment children don't get component name, only element name
-                        let prev_component = self.current_component_name.take();
-                        jsx_element.visit_mut_with(self);
-                        self.current_component_name = prev_component;
+                        self.with_root(false, |this| jsx_element.visit_mut_with(this));
                     }
                 }
                 JSXElementChild::JSXFragment(jsx_fragment) => {
                     // Fragments are always transparent containers
                     jsx_fragment.visit_mut_with(self);
                 }
+                JSXElementChild::JSXExprContainer(container) => {
+                    if is_fragment {
+                        self.process_jsx_expr_container(container);
+                    } else {
+                        self.with_root(false, |this| container.visit_mut_with(this));
+                    }
+                }
+                JSXElementChild::JSXSpreadChild(spread_child) => {
+                    self.with_root(false, |this| spread_child.visit_mut_with(this));
+                }
                 #[cfg(swc_ast_unknown)]
                 JSXElementChild::Unknown(..) => panic!("unknown jsx element child"),
                 _ => {}
             }
         }
+
+        if let Some(component_name) = host_component_name {
+            self.push_component_attr_down(element, &component_name);
+        }
     }
 
     fn process_jsx_fragment(&mut self, fragment: &mut JSXFragment) {
@@ -127,6 +583,12 @@ impl ReactComponentAnnotateVisitor {
                 JSXElementChild::JSXFragment(jsx_fragment) => {
                     jsx_fragment.visit_mut_with(self);
                 }
+                JSXElementChild::JSXExprContainer(container) => {
+                    self.process_jsx_expr_container(container);
+                }
+                JSXElementChild::JSXSpreadChild(spread_child) => {
+                    self.with_root(false, |this| spread_child.visit_mut_with(this));
+                }
                 #[cfg(swc_ast_unknown)]
                 JSXElementChild::Unknown(..) => panic!("unknown jsx element child"),
                 _ => {}
@@ -134,68 +596,86 @@ impl ReactComponentAnnotateVisitor {
         }
     }
 
-    fn add_attributes_to_element(&self, opening_element: &mut JSXOpeningElement) {
+    fn add_attributes_to_element(
+        &self,
+        opening_element: &mut JSXOpeningElement,
+        with_component_attr: bool,
+    ) {
         let element_name = get_element_name(&opening_element.name);
+        let element_binding = get_element_root_ident(&opening_element.name).map(Ident::to_id);
+        let annotations = self.element_annotations(
+            &element_name,
+            element_binding.as_ref(),
+            with_component_attr,
+            |name| has_attribute(opening_element, name),
+        );
+
+        opening_element.attrs.extend(
+            annotations
+                .into_iter()
+                .map(|(name, value)| create_jsx_attr_with_ident_and_str(name, &value)),
+        );
+    }
+
+    /// Attributes to annotate an element named `element_name` (and rooted at
+    /// `element_binding`) with, leaving out any the element already sets
+    /// (`has_attr`), and the component attribute unless `with_component_attr`
+    /// is set
+    fn element_annotations(
+        &self,
+        element_name: &str,
+        element_binding: Option<&Id>,
+        with_component_attr: bool,
+        has_attr: impl Fn(&str) -> bool,
+    ) -> Vec<(&IdentName, Str)> {
+        let mut annotations = Vec::new();
+        let current_component_name = self.current_component_name();
+        let root_component_name = self.scope().root_component_name();
 
         // Check if component should be ignored
-        if let Some(ref component_name) = self.current_component_name {
+        if let Some(component_name) = root_component_name {
             if self.should_ignore_component(component_name) {
-                return;
+                return annotations;
             }
         }
 
-        if self.should_ignore_component(&element_name) {
-            return;
+        if self.should_ignore_component(element_name) {
+            return annotations;
         }
 
-        let is_ignored_html = self.should_ignore_element(&element_name);
+        let is_ignored_html = self.should_ignore_element(element_name, element_binding);
         let add_element_attr = !is_ignored_html
-            && !has_attribute(opening_element, self.config.element_attr_name())
+            && !has_attr(self.config.element_attr_name())
             && (self.config.component_attr_name() != self.config.element_attr_name()
-                || self.current_component_name.is_none());
-        let add_component_attr = self.current_component_name.is_some()
-            && !has_attribute(opening_element, self.config.component_attr_name());
+                || current_component_name.is_none());
+        let add_component_attr = with_component_attr
+            && current_component_name.is_some()
+            && !has_attr(self.config.component_attr_name());
         let add_source_file_attr = self.source_file_name.is_some()
-            && (self.current_component_name.is_some() || !is_ignored_html)
-            && !has_attribute(opening_element, self.config.source_file_attr_name());
+            && (root_component_name.is_some() || !is_ignored_html)
+            && !has_attr(self.config.source_file_attr_name());
         let add_source_path_attr = self.source_file_path.is_some()
             && self.source_path_attr_ident.is_some()
-            && (self.current_component_name.is_some() || !is_ignored_html)
-            && !has_attribute(opening_element, self.config.source_path_attr_name());
-
-        let attr_count = usize::from(add_element_attr)
-            + usize::from(add_component_attr)
-            + usize::from(add_source_file_attr)
-            + usize::from(add_source_path_attr);
-
-        if attr_count > 0 {
-            opening_element.attrs.reserve(attr_count);
-        }
+            && (root_component_name.is_some() || !is_ignored_html)
+            && !has_attr(self.config.source_path_attr_name());
 
         if add_element_attr {
-            opening_element.attrs.push(create_jsx_attr_with_ident(
-                &self.element_attr_ident,
-                &element_name,
-            ));
+            // Local components are referred to by their displayName too
+            let element_attr_value = self
+                .display_name(element_binding, element_name)
+                .unwrap_or(element_name);
+            annotations.push((&self.element_attr_ident, create_str(element_attr_value)));
         }
 
         if add_component_attr {
-            if let Some(ref component_name) = self.current_component_name {
-                opening_element.attrs.push(create_jsx_attr_with_ident(
-                    &self.component_attr_ident,
-                    component_name,
-                ));
+            if let Some(component_name) = current_component_name {
+                annotations.push((&self.component_attr_ident, create_str(component_name)));
             }
         }
 
         if add_source_file_attr {
             if let Some(ref source_file) = self.source_file_name {
-                opening_element
-                    .attrs
-                    .push(create_jsx_attr_with_ident_and_str(
-                        &self.source_file_attr_ident,
-                        source_file,
-                    ));
+                annotations.push((&self.source_file_attr_ident, source_file.clone()));
             }
         }
 
@@ -203,325 +683,1687 @@ impl ReactComponentAnnotateVisitor {
             if let (Some(ref source_path), Some(ref source_path_attr_ident)) =
                 (&self.source_file_path, &self.source_path_attr_ident)
             {
-                opening_element
-                    .attrs
-                    .push(create_jsx_attr_with_ident_and_str(
-                        source_path_attr_ident,
-                        source_path,
-                    ));
+                annotations.push((source_path_attr_ident, source_path.clone()));
             }
         }
-    }
-
-    fn find_jsx_in_function_body(&mut self, func: &mut Function, component_name: String) {
-        if let Some(body) = &mut func.body {
-            self.current_component_name = Some(component_name);
-
-            // Look for return statements
-            for stmt in &mut body.stmts {
-                if let Stmt::Return(return_stmt) = stmt {
-                    if let Some(arg) = &mut return_stmt.arg {
-                        self.process_return_expression(arg);
-                    }
-                }
-            }
 
-            self.current_component_name = None;
-        }
+        annotations
     }
 
-    fn process_return_expression(&mut self, expr: &mut Expr) {
+    /// Visit a component defined by an expression: an arrow function, a
+    /// function expression, or either of those wrapped in a component
+    /// wrapper call such as `memo(() => ...)` or `forwardRef(function () {})`.
+    /// Anything else is visited as a regular expression.
+    fn visit_component_expr(&mut self, expr: &mut Expr, component_name: String) {
         match expr {
-            Expr::JSXElement(jsx_element) => {
-                jsx_element.visit_mut_with(self);
+            Expr::Arrow(arrow_func) => {
+                self.visit_component_arrow(arrow_func, component_name);
             }
-            Expr::JSXFragment(jsx_fragment) => {
-                jsx_fragment.visit_mut_with(self);
+            Expr::Fn(func_expr) => {
+                func_expr.ident.visit_mut_with(self);
+                self.visit_component_function(&mut func_expr.function, component_name);
             }
-            Expr::Cond(cond_expr) => {
-                // Handle ternary expressions
-                self.process_return_expression(&mut cond_expr.cons);
-                self.process_return_expression(&mut cond_expr.alt);
+            Expr::Class(class_expr) if self.is_component_class(&class_expr.class) => {
+                class_expr.ident.visit_mut_with(self);
+                self.visit_component_class(&mut class_expr.class, component_name);
             }
             Expr::Paren(paren_expr) => {
-                self.process_return_expression(&mut paren_expr.expr);
+                self.visit_component_expr(&mut paren_expr.expr, component_name);
             }
-            #[cfg(swc_ast_unknown)]
-            Expr::Unknown(..) => panic!("unknown expr"),
-            _ => {}
+            Expr::Call(call_expr) if self.is_component_wrapper(&call_expr.callee) => {
+                call_expr.callee.visit_mut_with(self);
+
+                // Wrappers such as memo(forwardRef(...)) can be nested
+                let mut args = call_expr.args.iter_mut();
+                match args.next() {
+                    Some(ExprOrSpread { spread: None, expr }) => {
+                        self.visit_component_expr(expr, component_name);
+                    }
+                    Some(arg) => arg.visit_mut_with(self),
+                    None => {}
+                }
+                for arg in args {
+                    arg.visit_mut_with(self);
+                }
+            }
+            _ => expr.visit_mut_with(self),
         }
     }
 
-    /// Check if a call expression matches styled(ComponentRef) pattern
-    fn is_styled_call_with_component_ref(&self, call_expr: &CallExpr) -> Option<String> {
-        // Check if we have a tracked styled import
-        let styled_name = self.styled_import.as_ref()?;
+    fn visit_component_arrow(&mut self, arrow_func: &mut ArrowExpr, component_name: String) {
+        self.visit_arrow_in_scope(arrow_func, |root_bindings| {
+            ComponentScope::component(component_name, root_bindings)
+        });
+    }
 
-        // Check if the callee is the styled identifier
-        let callee_name = match call_expr.callee.as_expr() {
-            Some(expr) => match expr.as_ref() {
-                Expr::Ident(ident) => ident.sym.as_ref(),
-                #[cfg(swc_ast_unknown)]
-                Expr::Unknown(..) => panic!("unknown expr"),
-                _ => return None,
-            },
-            _ => return None,
+    fn visit_component_function(&mut self, func: &mut Function, component_name: String) {
+        self.visit_function_in_scope(func, |root_bindings| {
+            ComponentScope::component(component_name, root_bindings)
+        });
+    }
+
+    /// Visit an arrow function rendering JSX in the scope built by
+    /// `make_scope` from the function's root bindings
+    fn visit_arrow_in_scope(
+        &mut self,
+        arrow_func: &mut ArrowExpr,
+        make_scope: impl FnOnce(FxHashSet<Id>) -> ComponentScope,
+    ) {
+        let root_bindings = match arrow_func.body.as_ref() {
+            BlockStmtOrExpr::BlockStmt(block) => {
+                collect_root_bindings(&block.stmts, &|name| self.is_transparent_element(name))
+            }
+            _ => Default::default(),
         };
 
-        if callee_name != styled_name {
-            return None;
-        }
+        self.with_scope(make_scope(root_bindings), |this| {
+            arrow_func.params.visit_mut_with(this);
 
-        // Check if the first argument is an identifier (component reference)
-        if let Some(ExprOrSpread { spread: None, expr }) = call_expr.args.first() {
-            if let Expr::Ident(ident) = expr.as_ref() {
-                return Some(ident.sym.to_string());
+            match arrow_func.body.as_mut() {
+                BlockStmtOrExpr::BlockStmt(block) => block.visit_mut_with(this),
+                // Direct expression return
+                BlockStmtOrExpr::Expr(expr) => this.visit_root_expr(expr),
+                #[cfg(swc_ast_unknown)]
+                _ => panic!("unknown block stmt or expr"),
             }
-        }
-
-        None
+        });
     }
 
-    /// Transform styled(ComponentRef) to styled(props => <ComponentRef data-element="..." {...props} />)
-    fn transform_styled_call(
-        &self,
-        call_expr: &mut CallExpr,
-        ref_component_name: String,
-        styled_component_name: String,
+    /// Visit a function rendering JSX in the scope built by `make_scope`
+    /// from the function's root bindings
+    fn visit_function_in_scope(
+        &mut self,
+        func: &mut Function,
+        make_scope: impl FnOnce(FxHashSet<Id>) -> ComponentScope,
     ) {
-        use swc_core::common::{SyntaxContext, DUMMY_SP};
+        let root_bindings = func
+            .body
+            .as_ref()
+            .map(|body| {
+                collect_root_bindings(&body.stmts, &|name| self.is_transparent_element(name))
+            })
+            .unwrap_or_default();
 
-        // Create the props parameter: props
-        let props_param = Pat::Ident(BindingIdent {
-            id: Ident::new("props".into(), DUMMY_SP, SyntaxContext::empty()),
-            type_ann: None,
+        // Return statements are picked up by visit_mut_return_stmt, wherever
+        // they are nested in the body's control flow
+        self.with_scope(make_scope(root_bindings), |this| {
+            func.visit_mut_children_with(this)
         });
+    }
 
-        // Build attributes in order: data attributes first, then spread
-        let mut attrs = Vec::with_capacity(
-            2 + usize::from(self.source_file_name.is_some())
-                + usize::from(
-                    self.source_path_attr_ident.is_some() && self.source_file_path.is_some(),
-                ),
-        );
-
-        // Add data-element attribute using the styled component variable name
-        attrs.push(create_jsx_attr(
-            self.config.element_attr_name(),
-            &styled_component_name,
-        ));
-
-        // Add data-source-file attribute
-        if let Some(ref source_file) = self.source_file_name {
-            attrs.push(create_jsx_attr_with_ident_and_str(
-                &self.source_file_attr_ident,
-                source_file,
-            ));
+    /// Owner of a callback rendered as part of the enclosing component, if
+    /// `expr` is a function and there is an enclosing component
+    fn render_callback_owner(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Arrow(_) | Expr::Fn(_) => self.enclosing_component_name(),
+            Expr::Paren(paren_expr) => self.render_callback_owner(&paren_expr.expr),
+            _ => None,
         }
+    }
 
-        // Add data-source-path attribute (only if explicitly configured)
-        if let (Some(ref source_path), Some(ref source_path_attr_ident)) =
-            (&self.source_file_path, &self.source_path_attr_ident)
-        {
-            attrs.push(create_jsx_attr_with_ident_and_str(
-                source_path_attr_ident,
-                source_path,
-            ));
+    /// Visit a callback whose JSX is rendered as part of its `owner`: a
+    /// render prop (`renderItem={(item) => <Row />}`), a function child
+    /// (`<Query>{(data) => <Table />}</Query>`) or a memoized callback
+    /// (`useMemo(() => <Chart />)`)
+    fn visit_render_callback(&mut self, expr: &mut Expr, owner: String) {
+        match expr {
+            Expr::Arrow(arrow_func) => self.visit_arrow_in_scope(arrow_func, |root_bindings| {
+                ComponentScope::render_callback(owner, root_bindings)
+            }),
+            Expr::Fn(func_expr) => {
+                func_expr.ident.visit_mut_with(self);
+                self.visit_function_in_scope(&mut func_expr.function, |root_bindings| {
+                    ComponentScope::render_callback(owner, root_bindings)
+                });
+            }
+            Expr::Paren(paren_expr) => self.visit_render_callback(&mut paren_expr.expr, owner),
+            _ => expr.visit_mut_with(self),
         }
-
-        // Add spread attribute AFTER data attributes: {...props}
-        attrs.push(JSXAttrOrSpread::SpreadElement(SpreadElement {
-            dot3_token: DUMMY_SP,
-            expr: Box::new(Expr::Ident(Ident::new(
-                "props".into(),
-                DUMMY_SP,
-                SyntaxContext::empty(),
-            ))),
-        }));
-
-        // Create JSX element: <ComponentRef data-element="..." data-source-file="..." {...props} />
-        let jsx_element = JSXElement {
-            span: DUMMY_SP,
-            opening: JSXOpeningElement {
-                name: JSXElementName::Ident(Ident::new(
-                    ref_component_name.into(),
-                    DUMMY_SP,
-                    SyntaxContext::empty(),
-                )),
-                span: DUMMY_SP,
-                attrs,
-                self_closing: true,
-                type_args: None,
-            },
-            children: vec![],
-            closing: None,
-        };
-
-        // Create arrow function: props => <ComponentRef data-element="..." {...props} />
-        let arrow_func = ArrowExpr {
-            span: DUMMY_SP,
-            ctxt: SyntaxContext::empty(),
-            params: vec![props_param],
-            body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::JSXElement(Box::new(
-                jsx_element,
-            ))))),
-            is_async: false,
-            is_generator: false,
-            type_params: None,
-            return_type: None,
-        };
-
-        // Replace the first argument with the arrow function
-        call_expr.args[0] = ExprOrSpread {
-            spread: None,
-            expr: Box::new(Expr::Arrow(arrow_func)),
-        };
     }
-}
 
-impl VisitMut for ReactComponentAnnotateVisitor {
-    noop_visit_mut_type!();
+    fn visit_component_class(&mut self, class: &mut Class, component_name: String) {
+        self.with_scope(ComponentScope::default(), |this| {
+            class.decorators.visit_mut_with(this);
+            class.super_class.visit_mut_with(this);
 
-    fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
-        // Track imports from @emotion/styled (only if enabled)
-        if self.config.experimental_rewrite_emotion_styled
-            && import_decl.src.value == "@emotion/styled"
-        {
-            for specifier in &import_decl.specifiers {
-                match specifier {
-                    // Default import: import styled from '@emotion/styled'
-                    ImportSpecifier::Default(default_import) => {
-                        self.styled_import = Some(default_import.local.sym.to_string());
+            for member in &mut class.body {
+                match member {
+                    // Look for render method
+                    ClassMember::Method(method)
+                        if !method.is_static && is_render_key(&method.key) =>
+                    {
+                        method.key.visit_mut_with(this);
+                        this.visit_component_function(&mut method.function, component_name.clone());
                     }
-                    // Named import: import { styled } from '@emotion/styled'
-                    ImportSpecifier::Named(named_import) => {
-                        // Check if the imported name is 'default' or 'styled'
-                        let imported_name = match &named_import.imported {
-                            Some(ModuleExportName::Ident(ident)) => Some(ident.sym.as_ref()),
-                            Some(ModuleExportName::Str(str)) => str.value.as_str(),
-                            None => Some(named_import.local.sym.as_ref()),
-                            #[cfg(swc_ast_unknown)]
-                            Some(_) => panic!("unknown module export name"),
-                        };
-
-                        if let Some(imported_name) = imported_name {
-                            if imported_name == "default" || imported_name == "styled" {
-                                self.styled_import = Some(named_import.local.sym.to_string());
-                            }
+                    // render = () => <div />
+                    ClassMember::ClassProp(class_prop)
+                        if !class_prop.is_static && is_render_key(&class_prop.key) =>
+                    {
+                        class_prop.decorators.visit_mut_with(this);
+                        class_prop.key.visit_mut_with(this);
+                        class_prop.type_ann.visit_mut_with(this);
+                        if let Some(value) = &mut class_prop.value {
+                            this.visit_component_expr(value, component_name.clone());
                         }
                     }
-                    ImportSpecifier::Namespace(_) => {}
-                    #[cfg(swc_ast_unknown)]
-                    _ => panic!("unknown import specifier"),
+                    _ => member.visit_mut_with(this),
                 }
             }
-        }
+        });
+    }
 
-        import_decl.visit_mut_children_with(self);
+    /// Identifier of a named function or class expression, looking through
+    /// parentheses and component wrapper calls:
+    /// `forwardRef(function Button(props, ref) { ... })` is named `Button`
+    fn component_expr_ident<'a>(&self, expr: &'a Expr) -> Option<&'a Ident> {
+        match expr {
+            Expr::Fn(FnExpr { ident, .. }) | Expr::Class(ClassExpr { ident, .. }) => ident.as_ref(),
+            Expr::Paren(paren_expr) => self.component_expr_ident(&paren_expr.expr),
+            Expr::Call(call_expr) if self.is_component_wrapper(&call_expr.callee) => {
+                match call_expr.args.first() {
+                    Some(ExprOrSpread { spread: None, expr }) => self.component_expr_ident(expr),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
     }
 
-    fn visit_mut_fn_decl(&mut self, func_decl: &mut FnDecl) {
-        let component_name = func_decl.ident.sym.to_string();
-        self.find_jsx_in_function_body(&mut func_decl.function, component_name);
-        func_decl.visit_mut_children_with(self);
+    /// Name for a default-exported component: its own identifier if it has
+    /// one, otherwise a name derived from the file (if enabled)
+    fn default_export_name(&self, ident: Option<&Ident>) -> Option<String> {
+        match ident {
+            Some(ident) => self.component_name_for(Some(&ident.to_id()), &ident.sym),
+            None if self.config.name_default_exports_from_file => self.file_component_name.clone(),
+            None => None,
+        }
     }
 
-    fn visit_mut_var_declarator(&mut self, var_declarator: &mut VarDeclarator) {
-        // Handle arrow functions and function expressions assigned to variables
-        if let Pat::Ident(ident) = &var_declarator.name {
-            let component_name = ident.id.sym.to_string();
+    /// Visit a value bound to `name`, either a variable (`Card`), a member
+    /// (`Card.Header`) or an object property (`parts.Header`). Functions are
+    /// annotated as components if the name passes the detection policy,
+    /// object literals have each of their properties named after the binding
+    /// and styled components render an element annotated with the name.
+    /// `binding` is the identifier the name is rooted at (`Card` for
+    /// `Card.Header`), if it has one.
+    fn visit_bound_expr(&mut self, expr: &mut Expr, binding: Option<&Id>, name: &str) {
+        let prev_label_local = self.label_local.replace(name.to_string());
 
-            if let Some(init) = &mut var_declarator.init {
-                match init.as_mut() {
-                    Expr::Call(call_expr) => {
-                        // Check if this is a styled(ComponentRef) pattern (only if enabled)
-                        if self.config.experimental_rewrite_emotion_styled {
-                            if let Some(ref_component_name) =
-                                self.is_styled_call_with_component_ref(call_expr)
-                            {
-                                // Transform styled(ComponentRef) to styled(props => <ComponentRef {...props} />)
-                                // Use the styled component variable name (e.g., StyledButton) as data-element
-                                self.transform_styled_call(
-                                    call_expr,
-                                    ref_component_name,
-                                    component_name.clone(),
-                                );
-                            }
+        // const StyledButton = styled(Button)`...`
+        if !self.visit_styled_expr(expr, Some(name)) {
+            match expr {
+                Expr::Object(object) => {
+                    self.visit_component_object(object, binding, Some(name));
+                }
+                _ => match self.component_name_for(binding, name) {
+                    Some(component_name) => self.visit_component_expr(expr, component_name),
+                    None => expr.visit_mut_with(self),
+                },
+            }
+        }
+
+        self.label_local = prev_label_local;
+    }
+
+    /// Visit an object literal whose properties may define components:
+    /// `const parts = { Header: () => <h1 />, Footer() { ... } }`. Property
+    /// names are qualified with `prefix`, the name the object is bound to
+    /// through `binding`.
+    fn visit_component_object(
+        &mut self,
+        object: &mut ObjectLit,
+        binding: Option<&Id>,
+        prefix: Option<&str>,
+    ) {
+        let qualify = |key: &str| match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.to_string(),
+        };
+
+        for prop in &mut object.props {
+            let PropOrSpread::Prop(prop) = prop else {
+                prop.visit_mut_with(self);
+                continue;
+            };
+
+            match prop.as_mut() {
+                Prop::KeyValue(key_value) => match get_prop_name(&key_value.key).map(qualify) {
+                    Some(name) => {
+                        key_value.key.visit_mut_with(self);
+                        self.visit_bound_expr(&mut key_value.value, binding, &name);
+                    }
+                    None => key_value.visit_mut_with(self),
+                },
+                Prop::Method(method) => {
+                    let component_name = get_prop_name(&method.key)
+                        .map(qualify)
+                        .and_then(|name| self.component_name_for(binding, &name));
+                    match component_name {
+                        Some(component_name) => {
+                            method.key.visit_mut_with(self);
+                            self.visit_component_function(&mut method.function, component_name);
                         }
+                        None => method.visit_mut_with(self),
                     }
-                    Expr::Arrow(arrow_func) => {
-                        self.current_component_name = Some(component_name);
-
-                        match arrow_func.body.as_mut() {
-                            BlockStmtOrExpr::BlockStmt(block) => {
-                                // Look for return statements in block
-                                for stmt in &mut block.stmts {
-                                    if let Stmt::Return(return_stmt) = stmt {
-                                        if let Some(arg) = &mut return_stmt.arg {
-                                            self.process_return_expression(arg);
-                                        }
-                                    }
+                }
+                _ => prop.visit_mut_with(self),
+            }
+        }
+    }
+
+    /// Visit the value assigned to a member: `Foo.Item = () => ...`,
+    /// `exports.Card = function () { ... }` or `module.exports = ...`.
+    /// `binding` is the identifier the target is rooted at.
+    fn visit_member_assignment(&mut self, binding: Option<&Id>, target: &str, value: &mut Expr) {
+        if target == "module.exports" {
+            if let Expr::Object(object) = value {
+                return self.visit_component_object(object, None, None);
+            }
+
+            // The module's only export is named like a default export: after
+            // its own name, or after the file if it's anonymous (and enabled)
+            let ident = self.component_expr_ident(value).cloned();
+            return match self.default_export_name(ident.as_ref()) {
+                Some(component_name) => self.visit_component_expr(value, component_name),
+                None => value.visit_mut_with(self),
+            };
+        }
+
+        // Named CommonJS exports are named after the export
+        let name = target
+            .strip_prefix("module.exports.")
+            .or_else(|| target.strip_prefix("exports."))
+            .unwrap_or(target);
+        self.visit_bound_expr(value, binding, name);
+    }
+
+    /// Visit an expression rendered as the output of the current component:
+    /// a returned value, or the value of a returned local variable. JSX
+    /// reached through conditionals, logical operators, sequences and
+    /// TypeScript wrappers is annotated as a component root, everything else
+    /// is visited as regular code.
+    fn visit_root_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::JSXElement(jsx_element) => {
+                self.with_root(true, |this| this.process_jsx_element(jsx_element));
+            }
+            Expr::JSXFragment(jsx_fragment) => {
+                self.with_root(true, |this| this.process_jsx_fragment(jsx_fragment));
+            }
+            // React.createElement("div"), _jsx("div", {})
+            Expr::Call(call_expr) if self.is_create_element_call(call_expr) => {
+                self.with_root(true, |this| this.process_create_element_call(call_expr));
+            }
+            Expr::Cond(cond_expr) => {
+                // Handle ternary expressions
+                self.visit_non_root(&mut cond_expr.test);
+                self.visit_root_expr(&mut cond_expr.cons);
+                self.visit_root_expr(&mut cond_expr.alt);
+            }
+            Expr::Paren(paren_expr) => {
+                self.visit_root_expr(&mut paren_expr.expr);
+            }
+            Expr::Bin(bin_expr) => match bin_expr.op {
+                // `isOpen && <Modal />` only ever returns the right-hand JSX
+                BinaryOp::LogicalAnd => {
+                    self.visit_non_root(&mut bin_expr.left);
+                    self.visit_root_expr(&mut bin_expr.right);
+                }
+                // `user ?? <Login />`, `content || <Empty />`
+                BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+                    self.visit_root_expr(&mut bin_expr.left);
+                    self.visit_root_expr(&mut bin_expr.right);
+                }
+                _ => self.visit_non_root(bin_expr),
+            },
+            Expr::Seq(seq_expr) => {
+                // Only the last expression of a sequence is returned
+                if let Some((last, rest)) = seq_expr.exprs.split_last_mut() {
+                    for expr in rest {
+                        self.visit_non_root(expr);
+                    }
+                    self.visit_root_expr(last);
+                }
+            }
+            // TypeScript wrappers: `as`, `satisfies`, `!`, `<T>` and `as const`
+            Expr::TsAs(ts_as) => self.visit_root_expr(&mut ts_as.expr),
+            Expr::TsSatisfies(ts_satisfies) => self.visit_root_expr(&mut ts_satisfies.expr),
+            Expr::TsNonNull(ts_non_null) => self.visit_root_expr(&mut ts_non_null.expr),
+            Expr::TsTypeAssertion(ts_type_assertion) => {
+                self.visit_root_expr(&mut ts_type_assertion.expr);
+            }
+            Expr::TsConstAssertion(ts_const_assertion) => {
+                self.visit_root_expr(&mut ts_const_assertion.expr);
+            }
+            // Returned identifiers were resolved up front by
+            // collect_root_bindings, their values are handled where assigned
+            _ => self.visit_non_root(expr),
+        }
+    }
+
+    #[inline]
+    fn visit_non_root<N: VisitMutWith<Self>>(&mut self, node: &mut N) {
+        self.with_root(false, |this| node.visit_mut_with(this));
+    }
+
+    /// Expression containers follow the same rules as the JSX children
+    /// around them: inside a transparent fragment, `{cond && <A />}` or
+    /// `{content}` renders component roots, while JSX nested deeper (e.g. in a
+    /// `.map()` callback) is only annotated at the element level
+    fn process_jsx_expr_container(&mut self, container: &mut JSXExprContainer) {
+        if let JSXExpr::Expr(expr) = &mut container.expr {
+            if let Some(owner) = self.render_callback_owner(expr) {
+                self.visit_render_callback(expr, owner);
+            } else if self.scope().root_component_name().is_some() {
+                self.visit_root_expr(expr);
+            } else {
+                self.visit_non_root(expr);
+            }
+        }
+    }
+
+    /// Check if a call creates an element through one of the configured
+    /// pragmas (only if enabled). Pragmas must be imported (`react`, its JSX
+    /// runtimes, or a library such as preact) or global, so local functions
+    /// that happen to share a pragma's name (`const h = (a, b) => a * b`)
+    /// are left alone
+    fn is_create_element_call(&self, call_expr: &CallExpr) -> bool {
+        if !self.config.annotate_create_element {
+            return false;
+        }
+
+        let is_pragma_binding = call_expr
+            .callee
+            .as_expr()
+            .and_then(|callee| get_expr_root_ident(callee))
+            .is_some_and(|root| {
+                root.ctxt == self.unresolved_ctxt
+                    || self.react_imports.contains_key(&root.to_id())
+                    || self.imported_bindings.contains(&root.to_id())
+            });
+
+        is_pragma_binding && self.is_callee_in(&call_expr.callee, &self.create_element_pragmas_set)
+    }
+
+    /// Check if a callee is one of `names`, either as written (`_jsx`) or as
+    /// imported from react (`import { jsx as _jsx } from 'react/jsx-runtime'`)
+    fn is_callee_in(&self, callee: &Callee, names: &FxHashSet<String>) -> bool {
+        let Some(callee) = callee.as_expr() else {
+            return false;
+        };
+        let Some(callee_name) = get_expr_name(callee) else {
+            return false;
+        };
+
+        names.contains(callee_name.as_ref())
+            || self
+                .resolve_react_name(callee)
+                .is_some_and(|name| names.contains(name.as_ref()))
+    }
+
+    /// Process a `React.createElement(type, props, ...children)` or
+    /// `jsx(type, { ...props, children }, key)` call the same way as the JSX
+    /// element it was compiled from, merging the attributes into the props
+    /// object (creating it if needed)
+    fn process_create_element_call(&mut self, call_expr: &mut CallExpr) {
+        self.visit_non_root(&mut call_expr.callee);
+
+        let element_type = match call_expr.args.first_mut() {
+            Some(ExprOrSpread { spread: None, expr }) => expr,
+            // createElement(...args) can't be analyzed
+            _ => return self.visit_non_root(&mut call_expr.args),
+        };
+
+        let element_name = match element_type.as_ref() {
+            Expr::Lit(Lit::Str(str)) => str.value.as_str().map(str::to_string),
+            expr => get_expr_name(expr).map(Cow::into_owned),
+        };
+        let element_binding = get_expr_root_ident(element_type).map(Ident::to_id);
+        // Fragments and other transparent components (but never host
+        // elements, which are passed as strings)
+        let is_fragment = !matches!(element_type.as_ref(), Expr::Lit(_))
+            && (self
+                .react_import_name(element_type)
+                .is_some_and(|name| matches!(name.as_str(), "Fragment" | "React.Fragment"))
+                || matches!(&element_name, Some(name) if self.is_transparent_component(name)));
+        self.visit_non_root(element_type);
+
+        // Props, with the automatic runtime's `children` prop being visited
+        // like the children of a JSX element
+        match call_expr.args.get_mut(1) {
+            Some(ExprOrSpread {
+                spread: None,
+                expr: props,
+            }) => match props.as_mut() {
+                Expr::Object(object) => {
+                    for prop in &mut object.props {
+                        match prop {
+                            PropOrSpread::Prop(prop) => match prop.as_mut() {
+                                Prop::KeyValue(key_value)
+                                    if get_prop_name(&key_value.key) == Some("children") =>
+                                {
+                                    self.visit_create_element_child(
+                                        &mut key_value.value,
+                                        is_fragment,
+                                    );
                                 }
-                            }
-                            BlockStmtOrExpr::Expr(expr) => {
-                                // Direct expression return
-                                self.process_return_expression(expr);
-                            }
+                                _ => self.visit_non_root(prop),
+                            },
+                            PropOrSpread::Spread(spread) => self.visit_non_root(spread),
                             #[cfg(swc_ast_unknown)]
-                            _ => panic!("unknown block stmt or expr"),
+                            _ => panic!("unknown prop or spread"),
+                        }
+                    }
+                }
+                _ => self.visit_non_root(props),
+            },
+            Some(arg) => self.visit_non_root(arg),
+            None => {}
+        }
+
+        if let (Some(element_name), false) = (element_name, is_fragment) {
+            self.add_attributes_to_call(call_expr, &element_name, element_binding.as_ref());
+        }
+
+        // createElement children (or the automatic runtime's key)
+        for arg in call_expr.args.iter_mut().skip(2) {
+            match arg {
+                ExprOrSpread { spread: None, expr } => {
+                    self.visit_create_element_child(expr, is_fragment);
+                }
+                _ => self.visit_non_root(arg),
+            }
+        }
+    }
+
+    /// Children of a fragment keep the role of the fragment, like the
+    /// expression containers of a JSX fragment, and children of any other
+    /// element are never component roots
+    fn visit_create_element_child(&mut self, child: &mut Expr, is_fragment: bool) {
+        if !is_fragment || self.scope().root_component_name().is_none() {
+            return self.visit_non_root(child);
+        }
+
+        match child {
+            // jsxs(Fragment, { children: [a, b] })
+            Expr::Array(array) => {
+                for elem in array.elems.iter_mut().flatten() {
+                    match elem {
+                        ExprOrSpread { spread: None, expr } => {
+                            self.visit_create_element_child(expr, is_fragment);
                         }
+                        _ => self.visit_non_root(elem),
+                    }
+                }
+            }
+            _ => self.visit_root_expr(child),
+        }
+    }
+
+    /// Merge the element's attributes into the props argument of a
+    /// createElement call: `null`, `undefined` or a missing argument become
+    /// a new object, and other expressions are spread into one
+    fn add_attributes_to_call(
+        &self,
+        call_expr: &mut CallExpr,
+        element_name: &str,
+        element_binding: Option<&Id>,
+    ) {
+        let props = match call_expr.args.get(1) {
+            Some(ExprOrSpread {
+                spread: None,
+                expr: props,
+            }) => Some(props.as_ref()),
+            // createElement(type, ...rest)
+            Some(_) => return,
+            None => None,
+        };
+        let annotations = self.element_annotations(
+            element_name,
+            element_binding,
+            true,
+            |name| matches!(props, Some(Expr::Object(object)) if has_prop(object, name)),
+        );
+
+        if annotations.is_empty() {
+            return;
+        }
+
+        let annotation_props = annotations
+            .into_iter()
+            .map(|(name, value)| create_prop_with_str(&name.sym, value));
+
+        let Some(ExprOrSpread { expr: props, .. }) = call_expr.args.get_mut(1) else {
+            call_expr
+                .args
+                .push(create_object(annotation_props.collect()).into());
+            return;
+        };
+
+        match props.as_mut() {
+            Expr::Object(object) => object.props.extend(annotation_props),
+            expr if is_nullish(expr) => **props = create_object(annotation_props.collect()),
+            _ => {
+                let spread = PropOrSpread::Spread(SpreadElement {
+                    dot3_token: DUMMY_SP,
+                    expr: props.take(),
+                });
+                **props = create_object(std::iter::once(spread).chain(annotation_props).collect());
+            }
+        }
+    }
+
+    /// Get the element a styled base creates: `styled(Button)`,
+    /// `styled(UI.Button)`, `styled(memo(Card))`, `styled('span')` or
+    /// `styled.div`
+    fn styled_target(&self, expr: &Expr) -> Option<StyledTarget> {
+        match expr {
+            // styled(Button), styled('span')
+            Expr::Call(call_expr) => {
+                match call_expr.callee.as_expr()?.as_ref() {
+                    Expr::Ident(ident) if self.styled_imports.contains(&ident.to_id()) => {}
+                    _ => return None,
+                }
+
+                match call_expr.args.first()? {
+                    ExprOrSpread { spread: None, expr } => match expr.as_ref() {
+                        Expr::Lit(Lit::Str(str)) => {
+                            Some(StyledTarget::Host(str.value.as_str()?.to_string()))
+                        }
+                        Expr::Call(call_expr) => Some(StyledTarget::ComponentExpr(
+                            self.wrapped_component_name(call_expr),
+                        )),
+                        expr => create_jsx_element_name(expr).map(StyledTarget::Component),
+                    },
+                    _ => None,
+                }
+            }
+            // styled.div
+            Expr::Member(member_expr) => match (&*member_expr.obj, &member_expr.prop) {
+                (Expr::Ident(obj), MemberProp::Ident(prop))
+                    if self.styled_imports.contains(&obj.to_id()) =>
+                {
+                    Some(StyledTarget::Host(prop.sym.to_string()))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Find the styled base of a styled component definition, walking the
+    /// tagged template and call chain built on top of it, e.g.
+    /// `styled.div.attrs(...)` in ``styled.div.attrs(...)`...` ``
+    fn find_styled_base_mut<'a>(&self, expr: &'a mut Expr) -> Option<&'a mut Expr> {
+        if self.styled_target(expr).is_some() {
+            return Some(expr);
+        }
+
+        match expr {
+            // styled.div`...`
+            Expr::TaggedTpl(tagged_tpl) => self.find_styled_base_mut(&mut tagged_tpl.tag),
+            // styled.button({...}), styled(Button)(...)
+            Expr::Call(call_expr) => match &mut call_expr.callee {
+                Callee::Expr(callee) => self.find_styled_base_mut(callee),
+                _ => None,
+            },
+            // styled.div.attrs
+            Expr::Member(member_expr) => self.find_styled_base_mut(&mut member_expr.obj),
+            _ => None,
+        }
+    }
+
+    /// Name of the component wrapped by a call such as `memo(Card)`
+    fn wrapped_component_name(&self, call_expr: &CallExpr) -> Option<String> {
+        if !self.is_component_wrapper(&call_expr.callee) {
+            return None;
+        }
 
-                        self.current_component_name = None;
+        match call_expr.args.first()? {
+            ExprOrSpread { spread: None, expr } => match expr.as_ref() {
+                Expr::Call(call_expr) => self.wrapped_component_name(call_expr),
+                expr => get_expr_name(expr).map(Cow::into_owned),
+            },
+            _ => None,
+        }
+    }
+
+    /// Visit a styled component definition, annotating the element it renders
+    /// and labeling its styles (if enabled). Returns false if `expr` isn't
+    /// one.
+    fn visit_styled_expr(&mut self, expr: &mut Expr, name: Option<&str>) -> bool {
+        if !self.config.experimental_rewrite_emotion_styled && self.emotion_label.is_none() {
+            return false;
+        }
+        let Some(base) = self.find_styled_base_mut(expr) else {
+            return false;
+        };
+
+        self.add_styled_label(base, name);
+        if self.config.experimental_rewrite_emotion_styled {
+            self.transform_styled_base(base, name);
+        }
+        expr.visit_mut_with(self);
+        true
+    }
+
+    /// Name of a styled component: `name`, or if it has none, the name of
+    /// the component it styles or of the file
+    fn styled_component_name(&self, target: &StyledTarget, name: Option<&str>) -> Option<String> {
+        name.map(str::to_string)
+            .or_else(|| target.component_name().map(Cow::into_owned))
+            .or_else(|| self.file_component_name.clone())
+    }
+
+    /// Add a `label` option to an emotion styled base: `styled(Button)`
+    /// becomes `styled(Button, { label: "StyledButton" })`, `styled.div`
+    /// becomes `styled("div", { label: "StyledDiv" })` and `styled(Button,
+    /// opts)` becomes `styled(Button, { ...opts, label: "StyledButton" })`
+    fn add_styled_label(&self, base: &mut Expr, name: Option<&str>) {
+        let Some(emotion_label) = &self.emotion_label else {
+            return;
+        };
+        let is_emotion_styled = match base {
+            Expr::Call(call_expr) => call_expr.callee.as_expr().is_some_and(|callee| {
+                matches!(&**callee, Expr::Ident(ident)
+                    if self.emotion_styled_imports.contains(&ident.to_id()))
+            }),
+            Expr::Member(member_expr) => matches!(&*member_expr.obj, Expr::Ident(ident)
+                if self.emotion_styled_imports.contains(&ident.to_id())),
+            _ => false,
+        };
+        if !is_emotion_styled {
+            return;
+        }
+        let Some(target) = self.styled_target(base) else {
+            return;
+        };
+        let local = self.styled_component_name(&target, name);
+        let Some(label) = emotion_label.label(local.as_deref()) else {
+            return;
+        };
+
+        match base {
+            Expr::Call(call_expr) => match call_expr.args.get_mut(1) {
+                None => call_expr.args.push(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(create_object(vec![create_prop_with_str(
+                        "label",
+                        create_str(&label),
+                    )])),
+                }),
+                Some(ExprOrSpread { spread: None, expr }) => match &mut **expr {
+                    Expr::Object(options) => {
+                        if !has_prop(options, "label") {
+                            options
+                                .props
+                                .push(create_prop_with_str("label", create_str(&label)));
+                        }
                     }
-                    Expr::Fn(func_expr) => {
-                        self.find_jsx_in_function_body(&mut func_expr.function, component_name);
+                    options => {
+                        *options = create_object(vec![
+                            PropOrSpread::Spread(SpreadElement {
+                                dot3_token: DUMMY_SP,
+                                expr: Box::new(options.take()),
+                            }),
+                            create_prop_with_str("label", create_str(&label)),
+                        ]);
                     }
-                    #[cfg(swc_ast_unknown)]
-                    Expr::Unknown(..) => panic!("unknown expr"),
-                    _ => {}
+                },
+                // styled(Button, ...options) can't be analyzed
+                Some(_) => {}
+            },
+            Expr::Member(member_expr) => {
+                let StyledTarget::Host(tag) = target else {
+                    return;
+                };
+                *base = Expr::Call(CallExpr {
+                    callee: Callee::Expr(member_expr.obj.take()),
+                    args: vec![
+                        ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(Expr::Lit(Lit::Str(create_str(&tag)))),
+                        },
+                        ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(create_object(vec![create_prop_with_str(
+                                "label",
+                                create_str(&label),
+                            )])),
+                        },
+                    ],
+                    ..Default::default()
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Label of `css` styles from @emotion/react, named after the binding or
+    /// component they are defined in
+    fn css_label(&self) -> Option<String> {
+        let local = self
+            .label_local
+            .clone()
+            .or_else(|| self.enclosing_component_name());
+        self.emotion_label.as_ref()?.label(local.as_deref())
+    }
+
+    /// Check if a callee or tag is `css` from @emotion/react
+    fn is_emotion_css(&self, expr: &Expr) -> bool {
+        matches!(expr, Expr::Ident(ident) if self.emotion_css_imports.contains(&ident.to_id()))
+    }
+
+    /// Rewrite a styled base so the element it renders is annotated:
+    /// `styled(Button)` gets a wrapper argument. Host elements (`styled.div`,
+    /// `styled('span')`) must still only receive valid DOM attributes and
+    /// leave `as` to the library, so they get `.attrs(...)` with
+    /// styled-components, a wrapper only passed valid DOM attributes through
+    /// `shouldForwardProp` with emotion, and a plain wrapper with libraries
+    /// treating components like host elements. They are otherwise left as
+    /// is, like ignored components.
+    fn transform_styled_base(&mut self, base: &mut Expr, name: Option<&str>) {
+        let Some(target) = self.styled_target(base) else {
+            return;
+        };
+
+        if target
+            .component_name()
+            .is_some_and(|component_name| self.should_ignore_component(&component_name))
+        {
+            return;
+        }
+        let Some(styled_component_name) = self.styled_component_name(&target, name) else {
+            return;
+        };
+
+        let styled_host = styled_base_ident(base)
+            .and_then(|styled| self.styled_host_imports.get(&styled.to_id()))
+            .copied();
+        let is_host = matches!(target, StyledTarget::Host(_));
+        let wrapper = match target {
+            StyledTarget::Host(tag) => match styled_host {
+                // styled.div -> styled.div.attrs({ "data-element": "StyledDiv" })
+                Some(StyledHost::Attrs) => {
+                    let attrs = self.create_styled_attrs(&styled_component_name);
+                    *base = Expr::Call(CallExpr {
+                        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                            span: DUMMY_SP,
+                            obj: Box::new(base.take()),
+                            prop: MemberProp::Ident(IdentName::new("attrs".into(), DUMMY_SP)),
+                        }))),
+                        args: vec![attrs.into()],
+                        ..Default::default()
+                    });
+                    return;
                 }
+                // styled.div -> styled(forwardRef((props, ref) => <div ... />), ...)
+                Some(StyledHost::ForwardProp | StyledHost::Wrapper) => self.create_styled_wrapper(
+                    JSXElementName::Ident(Ident::new_no_ctxt(tag.into(), DUMMY_SP)),
+                    &styled_component_name,
+                ),
+                None => return,
+            },
+            StyledTarget::Component(element_name) => {
+                let Expr::Call(call_expr) = base else {
+                    return;
+                };
+                let component = call_expr.args[0].expr.take();
+                self.create_styled_component_wrapper(
+                    *component,
+                    element_name,
+                    &styled_component_name,
+                )
+            }
+            // Bind the component to a parameter, so it's only created once:
+            // (Component => _hoistStatics(forwardRef(...), Component))(memo(Card))
+            StyledTarget::ComponentExpr(_) => {
+                let Expr::Call(call_expr) = base else {
+                    return;
+                };
+                let component = call_expr.args[0].expr.take();
+                let param = Ident::new(
+                    "Component".into(),
+                    DUMMY_SP,
+                    SyntaxContext::empty().apply_mark(Mark::new()),
+                );
+                let wrapper = self.create_styled_component_wrapper(
+                    Expr::Ident(param.clone()),
+                    JSXElementName::Ident(param.clone()),
+                    &styled_component_name,
+                );
+                let factory = ArrowExpr {
+                    params: vec![Pat::Ident(param.into())],
+                    body: Box::new(BlockStmtOrExpr::Expr(Box::new(wrapper))),
+                    ..Default::default()
+                };
+                Expr::Call(CallExpr {
+                    callee: Callee::Expr(Box::new(Expr::Paren(ParenExpr {
+                        span: DUMMY_SP,
+                        expr: Box::new(Expr::Arrow(factory)),
+                    }))),
+                    args: vec![ExprOrSpread {
+                        spread: None,
+                        expr: component,
+                    }],
+                    ..Default::default()
+                })
             }
+        };
+        let wrapper = ExprOrSpread {
+            spread: None,
+            expr: Box::new(wrapper),
+        };
+
+        match base {
+            Expr::Call(call_expr) => call_expr.args[0] = wrapper,
+            Expr::Member(member_expr) => {
+                *base = Expr::Call(CallExpr {
+                    callee: Callee::Expr(member_expr.obj.take()),
+                    args: vec![wrapper],
+                    ..Default::default()
+                });
+            }
+            _ => {}
         }
 
-        var_declarator.visit_mut_children_with(self);
+        // Only valid DOM attributes reach the host element, as they did
+        // before, and `as` is still handled by the library
+        if let (true, Some(StyledHost::ForwardProp), Expr::Call(call_expr)) =
+            (is_host, styled_host, base)
+        {
+            self.add_should_forward_prop(call_expr);
+        }
     }
 
-    fn visit_mut_class_decl(&mut self, class_decl: &mut ClassDecl) {
-        let component_name = class_decl.ident.sym.to_string();
-
-        // Look for render method
-        for member in &mut class_decl.class.body {
-            match member {
-                ClassMember::Method(method) => match &method.key {
-                    PropName::Ident(ident) => {
-                        if ident.sym.as_ref() == "render" {
-                            if let Some(body) = &mut method.function.body {
-                                self.current_component_name = Some(component_name.clone());
-
-                                // Look for return statements
-                                for stmt in &mut body.stmts {
-                                    if let Stmt::Return(return_stmt) = stmt {
-                                        if let Some(arg) = &mut return_stmt.arg {
-                                            self.process_return_expression(arg);
-                                        }
-                                    }
-                                }
+    /// Add `shouldForwardProp: isPropValid` to the options of a styled host
+    /// wrapper, before any options of its own: `styled(Wrapper, opts)`
+    /// becomes `styled(Wrapper, { shouldForwardProp: isPropValid, ...opts })`
+    fn add_should_forward_prop(&mut self, call_expr: &mut CallExpr) {
+        let has_own = match call_expr.args.get(1) {
+            Some(ExprOrSpread { spread: None, expr }) => {
+                matches!(&**expr, Expr::Object(options) if has_prop(options, "shouldForwardProp"))
+            }
+            Some(_) => true,
+            None => false,
+        };
+        if has_own {
+            return;
+        }
 
-                                self.current_component_name = None;
-                            }
+        let is_prop_valid = self.is_prop_valid_import.get_or_insert_with(|| {
+            Ident::new(
+                "_isPropValid".into(),
+                DUMMY_SP,
+                SyntaxContext::empty().apply_mark(Mark::new()),
+            )
+        });
+        let should_forward_prop = PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(IdentName::new("shouldForwardProp".into(), DUMMY_SP)),
+            value: Box::new(Expr::Ident(is_prop_valid.clone())),
+        })));
+
+        match call_expr.args.get_mut(1) {
+            None => call_expr.args.push(ExprOrSpread {
+                spread: None,
+                expr: Box::new(create_object(vec![should_forward_prop])),
+            }),
+            Some(ExprOrSpread { spread: None, expr }) => match &mut **expr {
+                Expr::Object(options) => options.props.insert(0, should_forward_prop),
+                options => {
+                    *options = create_object(vec![
+                        should_forward_prop,
+                        PropOrSpread::Spread(SpreadElement {
+                            dot3_token: DUMMY_SP,
+                            expr: Box::new(options.take()),
+                        }),
+                    ]);
+                }
+            },
+            Some(_) => {}
+        }
+    }
+
+    /// Create the `.attrs(...)` object annotating a styled host element
+    fn create_styled_attrs(&self, styled_component_name: &str) -> Expr {
+        let mut props = vec![create_prop_with_str(
+            self.config.element_attr_name(),
+            create_str(styled_component_name),
+        )];
+        if let Some(source_file) = &self.source_file_name {
+            props.push(create_prop_with_str(
+                &self.source_file_attr_ident.sym,
+                source_file.clone(),
+            ));
+        }
+        if let (Some(source_path), Some(source_path_attr_ident)) =
+            (&self.source_file_path, &self.source_path_attr_ident)
+        {
+            props.push(create_prop_with_str(
+                &source_path_attr_ident.sym,
+                source_path.clone(),
+            ));
+        }
+
+        create_object(props)
+    }
+
+    /// Create `_hoistStatics(forwardRef(...), Button)`, which keeps the
+    /// statics of `Button` (e.g. `Button.Item`) for libraries that hoist them
+    /// onto the styled component
+    fn create_styled_component_wrapper(
+        &mut self,
+        component: Expr,
+        element_name: JSXElementName,
+        styled_component_name: &str,
+    ) -> Expr {
+        let wrapper = self.create_styled_wrapper(element_name, styled_component_name);
+        let hoist_statics = self.hoist_statics_helper.get_or_insert_with(|| {
+            Ident::new(
+                "_hoistStatics".into(),
+                DUMMY_SP,
+                SyntaxContext::empty().apply_mark(Mark::new()),
+            )
+        });
+        create_call(Expr::Ident(hoist_statics.clone()), vec![wrapper, component])
+    }
+
+    /// Callee for `forwardRef`: an existing `forwardRef` or `React` value
+    /// import from `react`, or else a `forwardRef` import added to the module
+    fn forward_ref_callee(&mut self) -> Expr {
+        let binding = |name: &str| {
+            self.react_imports
+                .iter()
+                .find(|(id, imported)| {
+                    imported.as_str() == name && self.react_value_imports.contains(id)
+                })
+                .map(|((sym, ctxt), _)| Ident::new(sym.clone(), DUMMY_SP, *ctxt))
+        };
+
+        if let Some(forward_ref) = binding("forwardRef") {
+            return Expr::Ident(forward_ref);
+        }
+        if let Some(react) = binding("React") {
+            return Expr::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: Box::new(Expr::Ident(react)),
+                prop: MemberProp::Ident(IdentName::new("forwardRef".into(), DUMMY_SP)),
+            });
+        }
+
+        let forward_ref = self.forward_ref_import.get_or_insert_with(|| {
+            Ident::new(
+                "_forwardRef".into(),
+                DUMMY_SP,
+                SyntaxContext::empty().apply_mark(Mark::new()),
+            )
+        });
+        Expr::Ident(forward_ref.clone())
+    }
+
+    /// Create `forwardRef((props, ref) => <Element data-element="..." {...props} ref={ref} />)`
+    fn create_styled_wrapper(
+        &mut self,
+        element_name: JSXElementName,
+        styled_component_name: &str,
+    ) -> Expr {
+        let props = Ident::new("props".into(), DUMMY_SP, SyntaxContext::empty());
+        let ref_ident = Ident::new("ref".into(), DUMMY_SP, SyntaxContext::empty());
+
+        // Build attributes in order: data attributes first, then spread and ref
+        let mut attrs = Vec::with_capacity(
+            3 + usize::from(self.source_file_name.is_some())
+                + usize::from(
+                    self.source_path_attr_ident.is_some() && self.source_file_path.is_some(),
+                ),
+        );
+
+        // Add data-element attribute using the styled component variable name
+        attrs.push(create_jsx_attr(
+            self.config.element_attr_name(),
+            styled_component_name,
+        ));
+
+        // Add data-source-file attribute
+        if let Some(ref source_file) = self.source_file_name {
+            attrs.push(create_jsx_attr_with_ident_and_str(
+                &self.source_file_attr_ident,
+                source_file,
+            ));
+        }
+
+        // Add data-source-path attribute (only if explicitly configured)
+        if let (Some(ref source_path), Some(ref source_path_attr_ident)) =
+            (&self.source_file_path, &self.source_path_attr_ident)
+        {
+            attrs.push(create_jsx_attr_with_ident_and_str(
+                source_path_attr_ident,
+                source_path,
+            ));
+        }
+
+        // Add spread attribute AFTER data attributes: {...props}
+        attrs.push(JSXAttrOrSpread::SpreadElement(SpreadElement {
+            dot3_token: DUMMY_SP,
+            expr: Box::new(Expr::Ident(props.clone())),
+        }));
+
+        // Forward the ref: ref={ref}
+        attrs.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span: DUMMY_SP,
+            name: JSXAttrName::Ident(ref_ident.clone().into()),
+            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                span: DUMMY_SP,
+                expr: JSXExpr::Expr(Box::new(Expr::Ident(ref_ident.clone()))),
+            })),
+        }));
+
+        // Create JSX element: <Element data-element="..." data-source-file="..." {...props} ref={ref} />
+        let jsx_element = JSXElement {
+            span: DUMMY_SP,
+            opening: JSXOpeningElement {
+                name: element_name,
+                span: DUMMY_SP,
+                attrs,
+                self_closing: true,
+                type_args: None,
+            },
+            children: vec![],
+            closing: None,
+        };
+
+        // Create arrow function: (props, ref) => <Element ... />
+        let render = Expr::Arrow(ArrowExpr {
+            span: DUMMY_SP,
+            ctxt: SyntaxContext::empty(),
+            params: vec![Pat::Ident(props.into()), Pat::Ident(ref_ident.into())],
+            body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::JSXElement(Box::new(
+                jsx_element,
+            ))))),
+            is_async: false,
+            is_generator: false,
+            type_params: None,
+            return_type: None,
+        });
+
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(Box::new(self.forward_ref_callee())),
+            args: vec![ExprOrSpread {
+                spread: None,
+                expr: Box::new(render),
+            }],
+            ..Default::default()
+        })
+    }
+}
+
+/// Create the helper copying statics onto styled wrappers:
+/// `function _hoistStatics(target, source) { return Object.assign(target,
+/// Object.fromEntries(Object.entries(source).filter(([key]) => !["$$typeof",
+/// ...].includes(key)))); }`, leaving out React's and styled libraries'
+/// internal statics
+fn create_hoist_statics_helper(ident: Ident) -> Stmt {
+    let target = Ident::new_no_ctxt("target".into(), DUMMY_SP);
+    let source = Ident::new_no_ctxt("source".into(), DUMMY_SP);
+    let key = Ident::new_no_ctxt("key".into(), DUMMY_SP);
+    let skipped_statics = Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: constants::STYLED_WRAPPER_SKIPPED_STATICS
+            .iter()
+            .map(|name| Some(Expr::Lit(Lit::Str(create_str(name))).into()))
+            .collect(),
+    });
+    let is_hoisted = Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: UnaryOp::Bang,
+        arg: Box::new(create_call(
+            create_member(skipped_statics, "includes"),
+            vec![Expr::Ident(key.clone())],
+        )),
+    });
+    let filter = Expr::Arrow(ArrowExpr {
+        params: vec![Pat::Array(ArrayPat {
+            span: DUMMY_SP,
+            elems: vec![Some(Pat::Ident(key.into()))],
+            optional: false,
+            type_ann: None,
+        })],
+        body: Box::new(BlockStmtOrExpr::Expr(Box::new(is_hoisted))),
+        ..Default::default()
+    });
+
+    let entries = create_call(
+        create_member(create_ident("Object"), "entries"),
+        vec![Expr::Ident(source.clone())],
+    );
+    let statics = create_call(
+        create_member(create_ident("Object"), "fromEntries"),
+        vec![create_call(create_member(entries, "filter"), vec![filter])],
+    );
+    let assign = create_call(
+        create_member(create_ident("Object"), "assign"),
+        vec![Expr::Ident(target.clone()), statics],
+    );
+
+    Stmt::Decl(Decl::Fn(FnDecl {
+        ident,
+        declare: false,
+        function: Box::new(Function {
+            params: vec![target.into(), source.into()],
+            body: Some(BlockStmt {
+                stmts: vec![Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(Box::new(assign)),
+                })],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+    }))
+}
+
+/// The `styled` binding of a styled base: `styled` in `styled(Button)` or
+/// `styled.div`
+fn styled_base_ident(base: &Expr) -> Option<&Ident> {
+    match base {
+        Expr::Call(call_expr) => match call_expr.callee.as_expr()?.as_ref() {
+            Expr::Ident(ident) => Some(ident),
+            _ => None,
+        },
+        Expr::Member(member_expr) => member_expr.obj.as_ident(),
+        _ => None,
+    }
+}
+
+/// How a styled library's host elements (`styled.div`) are annotated
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StyledHost {
+    /// `styled.div.attrs({ "data-element": "..." })`
+    Attrs,
+    /// `styled(forwardRef(...), { shouldForwardProp: isPropValid })`
+    ForwardProp,
+    /// `styled(forwardRef(...))`
+    Wrapper,
+}
+
+/// Element created by a styled base
+enum StyledTarget {
+    /// `styled(Button)`, `styled(UI.Button)`
+    Component(JSXElementName),
+    /// `styled(memo(Card))`, with the name of the wrapped component
+    ComponentExpr(Option<String>),
+    /// `styled.div`, `styled('div')`
+    Host(String),
+}
+
+impl StyledTarget {
+    /// Name of the styled component, if it isn't a host element
+    fn component_name(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Self::Component(element_name) => Some(get_element_name(element_name)),
+            Self::ComponentExpr(component_name) => component_name.as_deref().map(Cow::Borrowed),
+            Self::Host(_) => None,
+        }
+    }
+}
+
+impl VisitMut for ReactComponentAnnotateVisitor {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        if self.config.use_display_name {
+            self.display_names = collect_display_names(&*module);
+        }
+
+        module.visit_mut_children_with(self);
+
+        // function _hoistStatics(target, source) { ... }, after the imports
+        if let Some(hoist_statics) = self.hoist_statics_helper.take() {
+            let index = module
+                .body
+                .iter()
+                .position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+                .unwrap_or(module.body.len());
+            module.body.insert(
+                index,
+                ModuleItem::Stmt(create_hoist_statics_helper(hoist_statics)),
+            );
+        }
+
+        // import _isPropValid from '@emotion/is-prop-valid';
+        if let Some(is_prop_valid) = self.is_prop_valid_import.take() {
+            let import_decl = ImportDecl {
+                span: DUMMY_SP,
+                specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                    span: DUMMY_SP,
+                    local: is_prop_valid,
+                })],
+                src: Box::new(create_str("@emotion/is-prop-valid")),
+                type_only: false,
+                with: None,
+                phase: Default::default(),
+            };
+            module
+                .body
+                .insert(0, ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
+        }
+
+        // import { forwardRef as _forwardRef } from 'react';
+        if let Some(forward_ref) = self.forward_ref_import.take() {
+            let import_decl = ImportDecl {
+                span: DUMMY_SP,
+                specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local: forward_ref,
+                    imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+                        "forwardRef".into(),
+                        DUMMY_SP,
+                    ))),
+                    is_type_only: false,
+                })],
+                src: Box::new(create_str("react")),
+                type_only: false,
+                with: None,
+                phase: Default::default(),
+            };
+            module
+                .body
+                .insert(0, ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
+        }
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        if self.config.use_display_name {
+            self.display_names = collect_display_names(&*script);
+        }
+
+        script.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
+        for specifier in &import_decl.specifiers {
+            self.imported_bindings.insert(specifier.local().to_id());
+
+            // Type-only imports are erased, and the JSX runtimes don't export
+            // forwardRef
+            let is_type_only = import_decl.type_only
+                || matches!(specifier, ImportSpecifier::Named(named) if named.is_type_only);
+            if import_decl.src.value == "react" && !is_type_only {
+                self.react_value_imports.insert(specifier.local().to_id());
+            }
+        }
+
+        // import { View, Text as RNText } from 'react-native'
+        if self.config.native && import_decl.src.value == "react-native" {
+            for specifier in &import_decl.specifiers {
+                if let ImportSpecifier::Named(named_import) = specifier {
+                    let imported = match &named_import.imported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                        Some(ModuleExportName::Str(str)) => {
+                            str.value.to_string_lossy().into_owned()
                         }
+                        None => named_import.local.sym.to_string(),
+                        #[cfg(swc_ast_unknown)]
+                        Some(_) => panic!("unknown module export name"),
+                    };
+                    self.react_native_imports
+                        .insert(named_import.local.to_id(), imported);
+                }
+            }
+        }
+
+        if matches!(
+            import_decl.src.value.as_str(),
+            Some("react" | "react/jsx-runtime" | "react/jsx-dev-runtime")
+        ) {
+            for specifier in &import_decl.specifiers {
+                let (local, imported) = match specifier {
+                    // import React from 'react', import * as React from 'react'
+                    ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                    | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                        (local, "React".to_string())
+                    }
+                    // import { Component as Base } from 'react'
+                    ImportSpecifier::Named(named_import) => {
+                        let imported = match &named_import.imported {
+                            Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                            Some(ModuleExportName::Str(str)) => {
+                                str.value.to_string_lossy().into_owned()
+                            }
+                            None => named_import.local.sym.to_string(),
+                            #[cfg(swc_ast_unknown)]
+                            Some(_) => panic!("unknown module export name"),
+                        };
+                        let imported = if imported == "default" {
+                            "React".to_string()
+                        } else {
+                            imported
+                        };
+                        (&named_import.local, imported)
                     }
                     #[cfg(swc_ast_unknown)]
-                    PropName::Unknown(..) => panic!("unknown prop name"),
-                    _ => {}
-                },
-                #[cfg(swc_ast_unknown)]
-                ClassMember::Unknown(..) => panic!("unknown class member"),
-                _ => {}
+                    _ => panic!("unknown import specifier"),
+                };
+                self.react_imports.insert(local.to_id(), imported);
+            }
+        }
+
+        // Track styled imports, and `css` from @emotion/react (only if enabled)
+        let source = import_decl.src.value.as_str().unwrap_or_default();
+        let styled_exports = self.config.styled_import_sources.get(source).filter(|_| {
+            self.config.experimental_rewrite_emotion_styled || self.emotion_label.is_some()
+        });
+        let is_emotion_styled = self.emotion_label.is_some() && source == "@emotion/styled";
+        let is_emotion_react = self.emotion_label.is_some() && source == "@emotion/react";
+
+        if styled_exports.is_some() || is_emotion_react {
+            for specifier in &import_decl.specifiers {
+                let (local, imported_name) = match specifier {
+                    // Default import: import styled from '@emotion/styled'
+                    ImportSpecifier::Default(default_import) => (&default_import.local, "default"),
+                    // Named import: import { styled } from '@mui/material/styles'
+                    ImportSpecifier::Named(named_import) => {
+                        let imported_name = match &named_import.imported {
+                            Some(ModuleExportName::Ident(ident)) => ident.sym.as_ref(),
+                            Some(ModuleExportName::Str(str)) => {
+                                str.value.as_str().unwrap_or_default()
+                            }
+                            None => named_import.local.sym.as_ref(),
+                            #[cfg(swc_ast_unknown)]
+                            Some(_) => panic!("unknown module export name"),
+                        };
+                        (&named_import.local, imported_name)
+                    }
+                    ImportSpecifier::Namespace(_) => continue,
+                    #[cfg(swc_ast_unknown)]
+                    _ => panic!("unknown import specifier"),
+                };
+
+                if styled_exports
+                    .is_some_and(|exports| exports.iter().any(|name| name == imported_name))
+                {
+                    self.styled_imports.insert(local.to_id());
+                    let styled_host = if constants::STYLED_ATTRS_SOURCES.contains(&source) {
+                        Some(StyledHost::Attrs)
+                    } else if constants::STYLED_FORWARD_PROP_SOURCES.contains(&source) {
+                        Some(StyledHost::ForwardProp)
+                    } else if constants::STYLED_WRAPPER_SOURCES.contains(&source) {
+                        Some(StyledHost::Wrapper)
+                    } else {
+                        None
+                    };
+                    if let Some(styled_host) = styled_host {
+                        self.styled_host_imports.insert(local.to_id(), styled_host);
+                    }
+                    if is_emotion_styled {
+                        self.emotion_styled_imports.insert(local.to_id());
+                    }
+                }
+                if is_emotion_react && imported_name == "css" {
+                    self.emotion_css_imports.insert(local.to_id());
+                }
+            }
+        }
+
+        import_decl.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_fn_decl(&mut self, func_decl: &mut FnDecl) {
+        let Some(component_name) =
+            self.component_name_for(Some(&func_decl.ident.to_id()), &func_decl.ident.sym)
+        else {
+            return func_decl.visit_mut_children_with(self);
+        };
+        func_decl.ident.visit_mut_with(self);
+        self.visit_component_function(&mut func_decl.function, component_name);
+    }
+
+    fn visit_mut_var_declarator(&mut self, var_declarator: &mut VarDeclarator) {
+        self.track_react_require(var_declarator);
+
+        let (Pat::Ident(ident), Some(init)) = (&var_declarator.name, &mut var_declarator.init)
+        else {
+            return var_declarator.visit_mut_children_with(self);
+        };
+
+        // const content = <Layout />; return content;
+        if self.scope().is_root_binding(&ident.id) {
+            var_declarator.name.visit_mut_with(self);
+            if let Some(init) = &mut var_declarator.init {
+                self.visit_root_expr(init);
+            }
+            return;
+        }
+
+        // Arrow functions, function expressions, wrapper calls such as
+        // memo(() => ...), objects of components and styled components
+        let binding = ident.id.to_id();
+        self.visit_bound_expr(init, Some(&binding), &binding.0);
+        var_declarator.name.visit_mut_with(self);
+    }
+
+    fn visit_mut_assign_expr(&mut self, assign_expr: &mut AssignExpr) {
+        if assign_expr.op != AssignOp::Assign {
+            return assign_expr.visit_mut_children_with(self);
+        }
+
+        match &assign_expr.left {
+            // let body; if (open) body = <Panel />; return <>{body}</>;
+            AssignTarget::Simple(SimpleAssignTarget::Ident(binding))
+                if self.scope().is_root_binding(&binding.id) =>
+            {
+                assign_expr.left.visit_mut_with(self);
+                self.visit_root_expr(&mut assign_expr.right);
+            }
+            // Foo.Item = () => <li />, module.exports = () => <App />
+            AssignTarget::Simple(SimpleAssignTarget::Member(member_expr)) => {
+                let binding = get_expr_root_ident(&member_expr.obj).map(Ident::to_id);
+                match get_member_name(member_expr) {
+                    Some(target) => {
+                        assign_expr.left.visit_mut_with(self);
+                        self.visit_member_assignment(
+                            binding.as_ref(),
+                            &target,
+                            &mut assign_expr.right,
+                        );
+                    }
+                    None => assign_expr.visit_mut_children_with(self),
+                }
+            }
+            _ => assign_expr.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_return_stmt(&mut self, return_stmt: &mut ReturnStmt) {
+        match &mut return_stmt.arg {
+            // Returns of nested functions are in their own (non-component) scope
+            Some(arg) if self.scope().is_component() => self.visit_root_expr(arg),
+            _ => return_stmt.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_class_decl(&mut self, class_decl: &mut ClassDecl) {
+        if !self.is_component_class(&class_decl.class) {
+            return class_decl.visit_mut_children_with(self);
+        }
+
+        let component_name = self.annotated_name(
+            Some(&class_decl.ident.to_id()),
+            class_decl.ident.sym.to_string(),
+        );
+        class_decl.ident.visit_mut_with(self);
+        self.visit_component_class(&mut class_decl.class, component_name);
+    }
+
+    fn visit_mut_export_default_decl(&mut self, export_default_decl: &mut ExportDefaultDecl) {
+        // `export default function Foo() {}` and `export default class {}` are
+        // expressions, so they never reach visit_mut_fn_decl/visit_mut_class_decl
+        match &mut export_default_decl.decl {
+            DefaultDecl::Fn(fn_expr) => match self.default_export_name(fn_expr.ident.as_ref()) {
+                Some(component_name) => {
+                    fn_expr.ident.visit_mut_with(self);
+                    self.visit_component_function(&mut fn_expr.function, component_name);
+                }
+                None => fn_expr.visit_mut_with(self),
+            },
+            DefaultDecl::Class(class_expr) => {
+                let component_name = if self.is_component_class(&class_expr.class) {
+                    self.default_export_name(class_expr.ident.as_ref())
+                } else {
+                    None
+                };
+
+                match component_name {
+                    Some(component_name) => {
+                        class_expr.ident.visit_mut_with(self);
+                        self.visit_component_class(&mut class_expr.class, component_name);
+                    }
+                    None => class_expr.visit_mut_with(self),
+                }
+            }
+            DefaultDecl::TsInterfaceDecl(_) => {}
+            #[cfg(swc_ast_unknown)]
+            _ => panic!("unknown default decl"),
+        }
+    }
+
+    fn visit_mut_export_default_expr(&mut self, export_default_expr: &mut ExportDefaultExpr) {
+        // export default styled(Card)`...`
+        if self.visit_styled_expr(&mut export_default_expr.expr, None) {
+            return;
+        }
+
+        // export default () => <div />, export default memo(() => <div />),
+        // export default forwardRef(function Button(props, ref) { ... })
+        let ident = self
+            .component_expr_ident(&export_default_expr.expr)
+            .cloned();
+        match self.default_export_name(ident.as_ref()) {
+            Some(component_name) => {
+                self.visit_component_expr(&mut export_default_expr.expr, component_name);
+            }
+            None => export_default_expr.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        self.with_scope(ComponentScope::default(), |this| {
+            function.visit_mut_children_with(this);
+        });
+    }
+
+    fn visit_mut_arrow_expr(&mut self, arrow_expr: &mut ArrowExpr) {
+        self.with_scope(ComponentScope::default(), |this| {
+            arrow_expr.visit_mut_children_with(this);
+        });
+    }
+
+    fn visit_mut_class_expr(&mut self, class_expr: &mut ClassExpr) {
+        // Class expressions that aren't bound to a name are named after
+        // their own identifier: wrap(class Foo extends Component {})
+        match &class_expr.ident {
+            Some(ident) if self.is_component_class(&class_expr.class) => {
+                let component_name =
+                    self.annotated_name(Some(&ident.to_id()), ident.sym.to_string());
+                class_expr.ident.visit_mut_with(self);
+                self.visit_component_class(&mut class_expr.class, component_name);
+            }
+            _ => class_expr.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        self.with_scope(ComponentScope::default(), |this| {
+            class.visit_mut_children_with(this);
+        });
+    }
+
+    fn visit_mut_getter_prop(&mut self, getter_prop: &mut GetterProp) {
+        self.with_scope(ComponentScope::default(), |this| {
+            getter_prop.visit_mut_children_with(this);
+        });
+    }
+
+    fn visit_mut_setter_prop(&mut self, setter_prop: &mut SetterProp) {
+        self.with_scope(ComponentScope::default(), |this| {
+            setter_prop.visit_mut_children_with(this);
+        });
+    }
+
+    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+        if self.is_create_element_call(call_expr) {
+            return self.process_create_element_call(call_expr);
+        }
+
+        // css({ color: 'red' }) -> css({ color: 'red' }, "label:Title;")
+        if call_expr
+            .callee
+            .as_expr()
+            .is_some_and(|callee| self.is_emotion_css(callee))
+        {
+            if let Some(label) = self.css_label() {
+                call_expr.args.push(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Str(create_str(&format!(
+                        "label:{};",
+                        label
+                    ))))),
+                });
+            }
+        }
+
+        // useMemo(() => <Chart />, [data])
+        if self.is_callee_in(&call_expr.callee, &self.render_callbacks_set) {
+            if let Some(ExprOrSpread { spread: None, expr }) = call_expr.args.first() {
+                if let Some(owner) = self.render_callback_owner(expr) {
+                    call_expr.callee.visit_mut_with(self);
+                    let mut args = call_expr.args.iter_mut();
+                    if let Some(callback) = args.next() {
+                        self.visit_render_callback(&mut callback.expr, owner);
+                    }
+                    args.for_each(|arg| arg.visit_mut_with(self));
+                    return;
+                }
+            }
+        }
+
+        call_expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_tagged_tpl(&mut self, tagged_tpl: &mut TaggedTpl) {
+        // css`color: red;` -> css`color: red;label:Title;`
+        if self.is_emotion_css(&tagged_tpl.tag) {
+            let ends_with_expr = !tagged_tpl.tpl.exprs.is_empty();
+            if let (Some(label), Some(quasi)) = (self.css_label(), tagged_tpl.tpl.quasis.last_mut())
+            {
+                // Terminate the last declaration, unless it already is. An
+                // empty last quasi follows an interpolation (`color: ${c}`)
+                let separator = match quasi.raw.trim_end().chars().last() {
+                    None if ends_with_expr => ";",
+                    None | Some(';') | Some('}') => "",
+                    Some(_) => ";",
+                };
+                let label = format!("{}label:{};", separator, label);
+                quasi.raw = format!("{}{}", quasi.raw, label).into();
+                quasi.cooked = quasi
+                    .cooked
+                    .as_ref()
+                    .map(|cooked| format!("{}{}", cooked.to_string_lossy(), label).into());
+            }
+        }
+
+        tagged_tpl.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_jsx_expr_container(&mut self, container: &mut JSXExprContainer) {
+        // Render props and function children
+        if let JSXExpr::Expr(expr) = &mut container.expr {
+            if let Some(owner) = self.render_callback_owner(expr) {
+                return self.visit_render_callback(expr, owner);
             }
         }
 
-        class_decl.visit_mut_children_with(self);
+        container.visit_mut_children_with(self);
     }
 
     fn visit_mut_jsx_element(&mut self, jsx_element: &mut JSXElement) {
@@ -558,7 +2400,8 @@ pub fn process_transform(
         FileName::Custom("unknown".to_string())
     };
 
-    let mut visitor = ReactComponentAnnotateVisitor::new(config, &filename);
+    let mut visitor = ReactComponentAnnotateVisitor::new(config, &filename)
+        .with_unresolved_mark(metadata.unresolved_mark);
     program.visit_mut_with(&mut visitor);
     program
 }
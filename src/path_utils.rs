@@ -81,3 +81,52 @@ pub fn extract_filename(filename: &FileName) -> Option<String> {
         _ => None,
     }
 }
+
+/// Derive a component name from the file name, using the same rules as
+/// `extract_filename`: `Home/index.tsx` becomes `Home` and `UserCard.tsx`
+/// becomes `UserCard`
+pub fn extract_component_name(filename: &FileName) -> Option<String> {
+    let file_name = extract_filename(filename)?;
+
+    // Index files are named after their parent directory
+    let base_name = match file_name.split_once('/') {
+        Some((parent, _)) => parent,
+        None => file_name.split('.').next().unwrap_or(&file_name),
+    };
+
+    if base_name.is_empty() || base_name == "index" {
+        None
+    } else {
+        Some(base_name.to_string())
+    }
+}
+
+fn extract_path_parts(filename: &FileName) -> Option<Vec<&str>> {
+    let path = match filename {
+        FileName::Real(path) => path.to_str()?,
+        FileName::Custom(custom) => custom.as_str(),
+        _ => return None,
+    };
+
+    Some(parse_path_with_detection(path))
+}
+
+/// File name without its extension: `components/Button.tsx` becomes `Button`
+pub fn extract_file_stem(filename: &FileName) -> Option<String> {
+    let parts = extract_path_parts(filename)?;
+    let file_name = parts.last()?;
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(*file_name, |(stem, _)| stem);
+
+    (!stem.is_empty()).then(|| stem.to_string())
+}
+
+/// Name of the directory containing the file: `components/Button.tsx`
+/// becomes `components`
+pub fn extract_dirname(filename: &FileName) -> Option<String> {
+    let parts = extract_path_parts(filename)?;
+    let dirname = parts.len().checked_sub(2).map(|index| parts[index])?;
+
+    (!dirname.is_empty()).then(|| dirname.to_string())
+}
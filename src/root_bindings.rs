@@ -0,0 +1,178 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::ecma::{
+    ast::*,
+    visit::{noop_visit_type, Visit, VisitWith},
+};
+
+/// Find the local variables of a component body whose values end up
+/// rendered as component roots: returned identifiers (`return content`),
+/// identifiers rendered directly in a returned fragment or other transparent
+/// element (`<>{body}</>`), and the variables those are assigned from
+/// (`const outer = inner`).
+///
+/// This only reads the statements of the body itself. Nested functions,
+/// classes and JSX are skipped, so each component body is scanned once and
+/// bindings are compared by syntax context, which keeps shadowed variables
+/// in nested scopes apart from the returned one.
+pub fn collect_root_bindings(stmts: &[Stmt], is_transparent: IsTransparent) -> FxHashSet<Id> {
+    let mut collector = RootBindingCollector {
+        is_transparent,
+        returned: Vec::new(),
+        assigned: FxHashMap::default(),
+    };
+    stmts.visit_with(&mut collector);
+
+    if collector.returned.is_empty() {
+        return FxHashSet::default();
+    }
+
+    // Resolving a binding may lead to another one (`const a = b`)
+    let mut root_bindings = FxHashSet::default();
+    let mut pending = collector.returned;
+    while let Some(id) = pending.pop() {
+        if let Some(sources) = collector.assigned.get(&id) {
+            pending.extend(sources.iter().cloned());
+        }
+        root_bindings.insert(id);
+    }
+
+    root_bindings
+}
+
+/// Whether a JSX element passes its role on to its children, like a fragment
+pub type IsTransparent<'a> = &'a dyn Fn(&JSXElementName) -> bool;
+
+/// Identifiers in the root positions of an expression, following the same
+/// shapes the visitor unwraps for returned JSX
+fn collect_root_idents(expr: &Expr, is_transparent: IsTransparent, out: &mut Vec<Id>) {
+    match expr {
+        Expr::Ident(ident) => out.push(ident.to_id()),
+        Expr::Paren(paren_expr) => collect_root_idents(&paren_expr.expr, is_transparent, out),
+        Expr::Cond(cond_expr) => {
+            collect_root_idents(&cond_expr.cons, is_transparent, out);
+            collect_root_idents(&cond_expr.alt, is_transparent, out);
+        }
+        Expr::Bin(bin_expr) => match bin_expr.op {
+            BinaryOp::LogicalAnd => collect_root_idents(&bin_expr.right, is_transparent, out),
+            BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+                collect_root_idents(&bin_expr.left, is_transparent, out);
+                collect_root_idents(&bin_expr.right, is_transparent, out);
+            }
+            _ => {}
+        },
+        Expr::Seq(seq_expr) => {
+            if let Some(last) = seq_expr.exprs.last() {
+                collect_root_idents(last, is_transparent, out);
+            }
+        }
+        Expr::TsAs(ts_as) => collect_root_idents(&ts_as.expr, is_transparent, out),
+        Expr::TsSatisfies(ts_satisfies) => {
+            collect_root_idents(&ts_satisfies.expr, is_transparent, out)
+        }
+        Expr::TsNonNull(ts_non_null) => collect_root_idents(&ts_non_null.expr, is_transparent, out),
+        Expr::TsTypeAssertion(ts_type_assertion) => {
+            collect_root_idents(&ts_type_assertion.expr, is_transparent, out)
+        }
+        Expr::TsConstAssertion(ts_const_assertion) => {
+            collect_root_idents(&ts_const_assertion.expr, is_transparent, out)
+        }
+        Expr::JSXFragment(jsx_fragment) => {
+            collect_fragment_idents(&jsx_fragment.children, is_transparent, out)
+        }
+        Expr::JSXElement(jsx_element) if is_transparent(&jsx_element.opening.name) => {
+            collect_fragment_idents(&jsx_element.children, is_transparent, out)
+        }
+        #[cfg(swc_ast_unknown)]
+        Expr::Unknown(..) => panic!("unknown expr"),
+        _ => {}
+    }
+}
+
+/// Fragments are transparent, so their expression children are roots too
+fn collect_fragment_idents(
+    children: &[JSXElementChild],
+    is_transparent: IsTransparent,
+    out: &mut Vec<Id>,
+) {
+    for child in children {
+        match child {
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => collect_root_idents(expr, is_transparent, out),
+            JSXElementChild::JSXFragment(jsx_fragment) => {
+                collect_fragment_idents(&jsx_fragment.children, is_transparent, out)
+            }
+            JSXElementChild::JSXElement(jsx_element)
+                if is_transparent(&jsx_element.opening.name) =>
+            {
+                collect_fragment_idents(&jsx_element.children, is_transparent, out)
+            }
+            #[cfg(swc_ast_unknown)]
+            JSXElementChild::Unknown(..) => panic!("unknown jsx element child"),
+            _ => {}
+        }
+    }
+}
+
+struct RootBindingCollector<'a> {
+    is_transparent: IsTransparent<'a>,
+    /// Identifiers rendered as roots by return statements
+    returned: Vec<Id>,
+    /// Identifiers in the root positions of each variable's values
+    assigned: FxHashMap<Id, Vec<Id>>,
+}
+
+impl RootBindingCollector<'_> {
+    fn record_assignment(&mut self, binding: &Ident, value: &Expr) {
+        let mut sources = Vec::new();
+        collect_root_idents(value, self.is_transparent, &mut sources);
+
+        if !sources.is_empty() {
+            self.assigned
+                .entry(binding.to_id())
+                .or_default()
+                .extend(sources);
+        }
+    }
+}
+
+impl Visit for RootBindingCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt) {
+        if let Some(arg) = &return_stmt.arg {
+            collect_root_idents(arg, self.is_transparent, &mut self.returned);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&var_declarator.name, &var_declarator.init) {
+            self.record_assignment(&binding.id, init);
+        }
+    }
+
+    fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+        if assign_expr.op == AssignOp::Assign {
+            if let AssignTarget::Simple(SimpleAssignTarget::Ident(binding)) = &assign_expr.left {
+                self.record_assignment(&binding.id, &assign_expr.right);
+            }
+        }
+
+        assign_expr.right.visit_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+
+    fn visit_getter_prop(&mut self, _: &GetterProp) {}
+
+    fn visit_setter_prop(&mut self, _: &SetterProp) {}
+
+    fn visit_jsx_element(&mut self, _: &JSXElement) {}
+
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {}
+}
@@ -0,0 +1,63 @@
+use rustc_hash::FxHashSet;
+use swc_core::ecma::ast::{Id, Ident};
+
+/// Component context for a function or class body. A scope is pushed at every
+/// function and class boundary, so nested definitions never leak their
+/// context into (or clear the context of) the enclosing component.
+#[derive(Debug, Default)]
+pub struct ComponentScope {
+    /// The component defined by this function or class, or the owner of a
+    /// render callback
+    pub component_name: Option<String>,
+    /// Whether this is a callback rendered as part of its owner component
+    /// (a render prop or a `useMemo` callback) rather than the component
+    /// itself
+    pub is_render_callback: bool,
+    /// Whether JSX is being processed in a root position, where it receives
+    /// the component attribute
+    pub in_root: bool,
+    /// Local variables whose values are rendered as component roots (e.g.
+    /// `const content = <Layout />; return content;`)
+    pub root_bindings: FxHashSet<Id>,
+}
+
+impl ComponentScope {
+    pub fn component(component_name: String, root_bindings: FxHashSet<Id>) -> Self {
+        Self {
+            component_name: Some(component_name),
+            is_render_callback: false,
+            in_root: false,
+            root_bindings,
+        }
+    }
+
+    pub fn render_callback(owner: String, root_bindings: FxHashSet<Id>) -> Self {
+        Self {
+            component_name: Some(owner),
+            is_render_callback: true,
+            in_root: false,
+            root_bindings,
+        }
+    }
+
+    #[inline]
+    pub fn is_component(&self) -> bool {
+        self.component_name.is_some()
+    }
+
+    /// The component (or render callback owner) JSX at the current
+    /// position is a root of
+    #[inline]
+    pub fn root_component_name(&self) -> Option<&str> {
+        if self.in_root {
+            self.component_name.as_deref()
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn is_root_binding(&self, ident: &Ident) -> bool {
+        !self.root_bindings.is_empty() && self.root_bindings.contains(&ident.to_id())
+    }
+}
@@ -0,0 +1,41 @@
+import { Component as Base, PureComponent } from 'react';
+import * as R from 'react';
+import { LitElement, html } from 'lit';
+import Backbone from 'backbone';
+
+class Aliased extends Base {
+  render() {
+    return <div>Aliased</div>;
+  }
+}
+
+class Pure extends PureComponent {
+  render() {
+    return <span>Pure</span>;
+  }
+}
+
+class Namespaced extends R.Component {
+  render() {
+    return <section>Namespaced</section>;
+  }
+}
+
+// Classes with a render method that are not React components
+class Greeting extends LitElement {
+  render() {
+    return html`<p>Hello</p>`;
+  }
+}
+
+class TodoView extends Backbone.View {
+  render() {
+    return <li>Todo</li>;
+  }
+}
+
+class Renderer {
+  render() {
+    return <canvas />;
+  }
+}
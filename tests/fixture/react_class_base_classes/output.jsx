@@ -0,0 +1,35 @@
+import { Component as Base, PureComponent } from 'react';
+import * as R from 'react';
+import { LitElement, html } from 'lit';
+import Backbone from 'backbone';
+class Aliased extends Base {
+    render() {
+        return <div data-component="Aliased" data-source-file="test.jsx">Aliased</div>;
+    }
+}
+class Pure extends PureComponent {
+    render() {
+        return <span data-component="Pure" data-source-file="test.jsx">Pure</span>;
+    }
+}
+class Namespaced extends R.Component {
+    render() {
+        return <section data-component="Namespaced" data-source-file="test.jsx">Namespaced</section>;
+    }
+}
+// Classes with a render method that are not React components
+class Greeting extends LitElement {
+    render() {
+        return html`<p>Hello</p>`;
+    }
+}
+class TodoView extends Backbone.View {
+    render() {
+        return <li>Todo</li>;
+    }
+}
+class Renderer {
+    render() {
+        return <canvas/>;
+    }
+}
@@ -0,0 +1,21 @@
+import React from 'react';
+import { AppComponent } from './app-component';
+import * as ui from './ui';
+
+class Dashboard extends AppComponent {
+  render() {
+    return <main>Dashboard</main>;
+  }
+}
+
+class Settings extends ui.BaseComponent {
+  render() {
+    return <form>Settings</form>;
+  }
+}
+
+class Legacy extends React.Component {
+  render() {
+    return <div>Legacy</div>;
+  }
+}
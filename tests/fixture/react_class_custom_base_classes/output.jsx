@@ -0,0 +1,18 @@
+import React from 'react';
+import { AppComponent } from './app-component';
+import * as ui from './ui';
+class Dashboard extends AppComponent {
+    render() {
+        return <main data-component="Dashboard" data-source-file="test.jsx">Dashboard</main>;
+    }
+}
+class Settings extends ui.BaseComponent {
+    render() {
+        return <form data-component="Settings" data-source-file="test.jsx">Settings</form>;
+    }
+}
+class Legacy extends React.Component {
+    render() {
+        return <div data-component="Legacy" data-source-file="test.jsx">Legacy</div>;
+    }
+}
@@ -0,0 +1,60 @@
+import React, { Component, PureComponent } from 'react';
+
+// Class expression bound to a variable
+const Card = class extends Component {
+  render() {
+    return <div>Card</div>;
+  }
+};
+
+// The binding takes precedence over the class's own name
+const Panel = class InnerPanel extends PureComponent {
+  render() {
+    return <section>Panel</section>;
+  }
+};
+
+// Class expression without a binding uses its own name
+registerWidget(class Widget extends React.Component {
+  render() {
+    return <aside>Widget</aside>;
+  }
+});
+
+// Render written as a class property
+class Toolbar extends Component {
+  render = () => <nav>Toolbar</nav>;
+}
+
+class Sidebar extends Component {
+  render = () => {
+    return <ul>Sidebar</ul>;
+  };
+}
+
+// Computed and string render keys
+class Footer extends Component {
+  ['render']() {
+    return <footer>Footer</footer>;
+  }
+}
+
+class Header extends Component {
+  'render'() {
+    return <header>Header</header>;
+  }
+}
+
+// Static members are not the render method
+class Helpers extends Component {
+  static render() {
+    return <span>Static</span>;
+  }
+}
+
+// Not a component: no React base class
+const Plain = class {
+  render() {
+    return <p>Plain</p>;
+  }
+};
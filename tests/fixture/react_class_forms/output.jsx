@@ -0,0 +1,51 @@
+import React, { Component, PureComponent } from 'react';
+// Class expression bound to a variable
+const Card = class extends Component {
+    render() {
+        return <div data-component="Card" data-source-file="test.jsx">Card</div>;
+    }
+};
+// The binding takes precedence over the class's own name
+const Panel = class InnerPanel extends PureComponent {
+    render() {
+        return <section data-component="Panel" data-source-file="test.jsx">Panel</section>;
+    }
+};
+// Class expression without a binding uses its own name
+registerWidget(class Widget extends React.Component {
+    render() {
+        return <aside data-component="Widget" data-source-file="test.jsx">Widget</aside>;
+    }
+});
+// Render written as a class property
+class Toolbar extends Component {
+    render = ()=><nav data-component="Toolbar" data-source-file="test.jsx">Toolbar</nav>;
+}
+class Sidebar extends Component {
+    render = ()=>{
+        return <ul data-component="Sidebar" data-source-file="test.jsx">Sidebar</ul>;
+    };
+}
+// Computed and string render keys
+class Footer extends Component {
+    ['render']() {
+        return <footer data-component="Footer" data-source-file="test.jsx">Footer</footer>;
+    }
+}
+class Header extends Component {
+    'render'() {
+        return <header data-component="Header" data-source-file="test.jsx">Header</header>;
+    }
+}
+// Static members are not the render method
+class Helpers extends Component {
+    static render() {
+        return <span>Static</span>;
+    }
+}
+// Not a component: no React base class
+const Plain = class {
+    render() {
+        return <p>Plain</p>;
+    }
+};
@@ -0,0 +1,7 @@
+const React = require('react');
+
+module.exports = () => (
+  <div>
+    <h1>Settings</h1>
+  </div>
+);
@@ -0,0 +1,4 @@
+const React = require('react');
+module.exports = ()=><div data-component="Settings" data-source-file="Settings.jsx">
+    <h1>Settings</h1>
+  </div>;
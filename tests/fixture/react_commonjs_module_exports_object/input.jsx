@@ -0,0 +1,8 @@
+const React = require('react');
+
+module.exports = {
+  Avatar: ({ src }) => <img src={src} />,
+  Profile() {
+    return <section>Profile</section>;
+  },
+};
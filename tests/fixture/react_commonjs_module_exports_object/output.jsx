@@ -0,0 +1,7 @@
+const React = require('react');
+module.exports = {
+    Avatar: ({ src })=><img src={src} data-component="Avatar" data-source-file="test.jsx"/>,
+    Profile () {
+        return <section data-component="Profile" data-source-file="test.jsx">Profile</section>;
+    }
+};
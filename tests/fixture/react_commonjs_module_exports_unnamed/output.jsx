@@ -0,0 +1,6 @@
+const React = require('react');
+// Anonymous module exports are only named after the file when
+// name-default-exports-from-file is enabled
+module.exports = ()=><div>
+    <h1>Settings</h1>
+  </div>;
@@ -0,0 +1,51 @@
+import React from 'react';
+
+// The attribute goes to the first host element in document order
+function MyCard({ title }) {
+  return (
+    <Box>
+      <Header>
+        <h2>{title}</h2>
+      </Header>
+      <div>Body</div>
+    </Box>
+  );
+}
+
+// Host roots are annotated as usual
+function Plain() {
+  return (
+    <section>
+      <Box />
+    </section>
+  );
+}
+
+// Custom elements are host elements too
+const Widget = () => (
+  <Layout>
+    <my-widget />
+  </Layout>
+);
+
+// Falls back to the root without host descendants
+const Composite = () => (
+  <Layout>
+    <Sidebar />
+  </Layout>
+);
+
+// Fragment roots push each of their roots down separately
+function Both() {
+  return (
+    <>
+      <Box>
+        <span>One</span>
+      </Box>
+      <Box>
+        <Icon />
+        <p>Two</p>
+      </Box>
+    </>
+  );
+}
@@ -0,0 +1,36 @@
+import React from 'react';
+// The attribute goes to the first host element in document order
+function MyCard({ title }) {
+    return <Box data-element="Box" data-source-file="test.jsx">
+      <Header data-element="Header" data-source-file="test.jsx">
+        <h2 data-component="MyCard">{title}</h2>
+      </Header>
+      <div>Body</div>
+    </Box>;
+}
+// Host roots are annotated as usual
+function Plain() {
+    return <section data-component="Plain" data-source-file="test.jsx">
+      <Box data-element="Box" data-source-file="test.jsx"/>
+    </section>;
+}
+// Custom elements are host elements too
+const Widget = ()=><Layout data-element="Layout" data-source-file="test.jsx">
+    <my-widget data-element="my-widget" data-source-file="test.jsx" data-component="Widget"/>
+  </Layout>;
+// Falls back to the root without host descendants
+const Composite = ()=><Layout data-element="Layout" data-component="Composite" data-source-file="test.jsx">
+    <Sidebar data-element="Sidebar" data-source-file="test.jsx"/>
+  </Layout>;
+// Fragment roots push each of their roots down separately
+function Both() {
+    return <>
+      <Box data-element="Box" data-source-file="test.jsx">
+        <span data-component="Both">One</span>
+      </Box>
+      <Box data-element="Box" data-source-file="test.jsx">
+        <Icon data-element="Icon" data-source-file="test.jsx"/>
+        <p data-component="Both">Two</p>
+      </Box>
+    </>;
+}
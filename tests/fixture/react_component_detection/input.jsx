@@ -0,0 +1,29 @@
+// Helpers that return JSX are not components
+function renderRow(row) {
+    return <tr><td>{row.label}</td></tr>;
+}
+
+const formatCell = (value) => <span className="cell">{value}</span>;
+
+function Table({ rows }) {
+    // Nested helpers render for the enclosing component
+    const getIcon = (row) => {
+        return <img src={row.icon} />;
+    };
+
+    function renderHeader() {
+        return <thead><tr><th>Label</th></tr></thead>;
+    }
+
+    return (
+        <table>
+            {renderHeader()}
+            <tbody>{rows.map(renderRow)}</tbody>
+            {rows.map(getIcon)}
+        </table>
+    );
+}
+
+export default function summary() {
+    return <p>Summary</p>;
+}
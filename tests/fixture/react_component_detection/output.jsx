@@ -0,0 +1,22 @@
+// Helpers that return JSX are not components
+function renderRow(row) {
+    return <tr><td>{row.label}</td></tr>;
+}
+const formatCell = (value)=><span className="cell">{value}</span>;
+function Table({ rows }) {
+    // Nested helpers render for the enclosing component
+    const getIcon = (row)=>{
+        return <img src={row.icon} data-component="Table" data-source-file="test.jsx"/>;
+    };
+    function renderHeader() {
+        return <thead data-component="Table" data-source-file="test.jsx"><tr><th>Label</th></tr></thead>;
+    }
+    return <table data-component="Table" data-source-file="test.jsx">
+            {renderHeader()}
+            <tbody>{rows.map(renderRow)}</tbody>
+            {rows.map(getIcon)}
+        </table>;
+}
+export default function summary() {
+    return <p>Summary</p>;
+}
@@ -0,0 +1,22 @@
+// With "any", every named function returning JSX is a component
+function renderRow(row) {
+    return <tr data-component="renderRow" data-source-file="test.jsx"><td>{row.label}</td></tr>;
+}
+const formatCell = (value)=><span className="cell" data-component="formatCell" data-source-file="test.jsx">{value}</span>;
+function Table({ rows }) {
+    // Nested helpers are components of their own
+    const getIcon = (row)=>{
+        return <img src={row.icon} data-component="getIcon" data-source-file="test.jsx"/>;
+    };
+    function renderHeader() {
+        return <thead data-component="renderHeader" data-source-file="test.jsx"><tr><th>Label</th></tr></thead>;
+    }
+    return <table data-component="Table" data-source-file="test.jsx">
+            {renderHeader()}
+            <tbody>{rows.map(renderRow)}</tbody>
+            {rows.map(getIcon)}
+        </table>;
+}
+export default function summary() {
+    return <p data-component="summary" data-source-file="test.jsx">Summary</p>;
+}
@@ -0,0 +1,13 @@
+function Avatar({ src }) {
+    return <img src={src} />;
+}
+
+const homeScreen = () => (
+    <main>
+        <Avatar src="/me.png" />
+    </main>
+);
+
+function renderFooter() {
+    return <footer>Footer</footer>;
+}
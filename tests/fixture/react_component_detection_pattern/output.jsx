@@ -0,0 +1,9 @@
+function Avatar({ src }) {
+    return <img src={src} data-component="Avatar" data-source-file="test.jsx"/>;
+}
+const homeScreen = ()=><main data-component="homeScreen" data-source-file="test.jsx">
+        <Avatar src="/me.png" data-element="Avatar" data-source-file="test.jsx"/>
+    </main>;
+function renderFooter() {
+    return <footer>Footer</footer>;
+}
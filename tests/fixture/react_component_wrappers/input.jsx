@@ -0,0 +1,34 @@
+import React, { memo, forwardRef } from 'react';
+import * as R from 'react';
+import { memo as m } from 'react';
+import { observer } from 'mobx-react-lite';
+
+const Button = React.memo(() => {
+  return <button>Click me</button>;
+});
+
+const Input = forwardRef((props, ref) => <input ref={ref} {...props} />);
+
+const Card = memo(forwardRef(function Card(props, ref) {
+  return (
+    <div ref={ref}>
+      <h2>{props.title}</h2>
+    </div>
+  );
+}));
+
+const Store = observer(() => <section>Store</section>);
+
+const Panel = React.forwardRef((props, ref) => (
+  <CustomPanel ref={ref}>
+    <p>Panel content</p>
+  </CustomPanel>
+));
+
+// Not a configured wrapper, should not be annotated as a component
+const Tracked = withTracking(() => <div>Tracked</div>);
+
+// Aliased imports from react are resolved
+const Badge = R.memo(() => <span>New</span>);
+
+const Tag = m(R.forwardRef((props, ref) => <em ref={ref}>{props.label}</em>));
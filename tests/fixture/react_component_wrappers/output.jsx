@@ -0,0 +1,22 @@
+import React, { memo, forwardRef } from 'react';
+import * as R from 'react';
+import { memo as m } from 'react';
+import { observer } from 'mobx-react-lite';
+const Button = React.memo(()=>{
+    return <button data-component="Button" data-source-file="test.jsx">Click me</button>;
+});
+const Input = forwardRef((props, ref)=><input ref={ref} {...props} data-component="Input" data-source-file="test.jsx"/>);
+const Card = memo(forwardRef(function Card(props, ref) {
+    return <div ref={ref} data-component="Card" data-source-file="test.jsx">
+      <h2>{props.title}</h2>
+    </div>;
+}));
+const Store = observer(()=><section data-component="Store" data-source-file="test.jsx">Store</section>);
+const Panel = React.forwardRef((props, ref)=><CustomPanel ref={ref} data-element="CustomPanel" data-component="Panel" data-source-file="test.jsx">
+    <p>Panel content</p>
+  </CustomPanel>);
+// Not a configured wrapper, should not be annotated as a component
+const Tracked = withTracking(()=><div>Tracked</div>);
+// Aliased imports from react are resolved
+const Badge = R.memo(()=><span data-component="Badge" data-source-file="test.jsx">New</span>);
+const Tag = m(R.forwardRef((props, ref)=><em ref={ref} data-component="Tag" data-source-file="test.jsx">{props.label}</em>));
@@ -0,0 +1,37 @@
+import React from 'react';
+import { h } from 'preact';
+
+function App({ title, children }) {
+  return React.createElement(
+    'div',
+    { className: 'app' },
+    React.createElement('h1', null, title),
+    React.createElement(Button, { onClick: () => {} }, 'Click me'),
+    children
+  );
+}
+
+// Missing and non-literal props
+const Card = (props) => React.createElement(Panel, props, React.createElement('span'));
+
+const Empty = () => React.createElement('section');
+
+// Attributes that are already set are left alone
+const Tagged = () => React.createElement('div', { 'data-component': 'Custom' });
+
+// Fragments are transparent
+const List = ({ items }) =>
+  React.createElement(
+    React.Fragment,
+    null,
+    React.createElement('ul', null),
+    items.length > 0 && React.createElement(Footer, undefined)
+  );
+
+// Other pragmas
+function Preact() {
+  return h('main', null, h(Header));
+}
+
+// JSX and createElement calls can be mixed
+const Mixed = () => <div>{React.createElement(Icon, { name: 'star' })}</div>;
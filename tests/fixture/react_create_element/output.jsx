@@ -0,0 +1,54 @@
+import React from 'react';
+import { h } from 'preact';
+function App({ title, children }) {
+    return React.createElement('div', {
+        className: 'app',
+        "data-component": "App",
+        "data-source-file": "test.jsx"
+    }, React.createElement('h1', null, title), React.createElement(Button, {
+        onClick: ()=>{},
+        "data-element": "Button",
+        "data-source-file": "test.jsx"
+    }, 'Click me'), children);
+}
+// Missing and non-literal props
+const Card = (props)=>React.createElement(Panel, {
+        ...props,
+        "data-element": "Panel",
+        "data-component": "Card",
+        "data-source-file": "test.jsx"
+    }, React.createElement('span'));
+const Empty = ()=>React.createElement('section', {
+        "data-component": "Empty",
+        "data-source-file": "test.jsx"
+    });
+// Attributes that are already set are left alone
+const Tagged = ()=>React.createElement('div', {
+        'data-component': 'Custom',
+        "data-source-file": "test.jsx"
+    });
+// Fragments are transparent
+const List = ({ items })=>React.createElement(React.Fragment, null, React.createElement('ul', {
+        "data-component": "List",
+        "data-source-file": "test.jsx"
+    }), items.length > 0 && React.createElement(Footer, {
+        "data-element": "Footer",
+        "data-component": "List",
+        "data-source-file": "test.jsx"
+    }));
+// Other pragmas
+function Preact() {
+    return h('main', {
+        "data-component": "Preact",
+        "data-source-file": "test.jsx"
+    }, h(Header, {
+        "data-element": "Header",
+        "data-source-file": "test.jsx"
+    }));
+}
+// JSX and createElement calls can be mixed
+const Mixed = ()=><div data-component="Mixed" data-source-file="test.jsx">{React.createElement(Icon, {
+        name: 'star',
+        "data-element": "Icon",
+        "data-source-file": "test.jsx"
+    })}</div>;
@@ -0,0 +1,15 @@
+import React from 'react';
+
+// Local functions sharing a pragma's name aren't element factories
+const h = (a, b) => a * b;
+
+function Grid({ w, rows }) {
+  const area = h(w, rows);
+  return React.createElement('div', null, area);
+}
+
+function Chart({ data }) {
+  const createElement = (type, props) => ({ type, ...props });
+  const node = createElement(Bar, data);
+  return React.createElement(Canvas, { node });
+}
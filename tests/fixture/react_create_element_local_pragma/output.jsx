@@ -0,0 +1,23 @@
+import React from 'react';
+// Local functions sharing a pragma's name aren't element factories
+const h = (a, b)=>a * b;
+function Grid({ w, rows }) {
+    const area = h(w, rows);
+    return React.createElement('div', {
+        "data-component": "Grid",
+        "data-source-file": "test.jsx"
+    }, area);
+}
+function Chart({ data }) {
+    const createElement = (type, props)=>({
+            type,
+            ...props
+        });
+    const node = createElement(Bar, data);
+    return React.createElement(Canvas, {
+        node,
+        "data-element": "Canvas",
+        "data-component": "Chart",
+        "data-source-file": "test.jsx"
+    });
+}
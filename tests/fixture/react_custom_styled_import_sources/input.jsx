@@ -0,0 +1,8 @@
+import { styled } from '@acme/design-system';
+import emotionStyled from '@emotion/styled';
+import { Button, Card } from './ui';
+
+const StyledButton = styled(Button);
+
+// @emotion/styled is not configured
+const StyledCard = emotionStyled(Card);
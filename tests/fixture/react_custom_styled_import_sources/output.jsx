@@ -0,0 +1,38 @@
+import { forwardRef as _forwardRef } from "react";
+import { styled } from '@acme/design-system';
+import emotionStyled from '@emotion/styled';
+import { Button, Card } from './ui';
+function _hoistStatics(target, source) {
+    return Object.assign(target, Object.fromEntries(Object.entries(source).filter(([key])=>![
+            "$$typeof",
+            "render",
+            "type",
+            "compare",
+            "displayName",
+            "defaultProps",
+            "propTypes",
+            "contextType",
+            "contextTypes",
+            "childContextTypes",
+            "getDefaultProps",
+            "getDerivedStateFromProps",
+            "getDerivedStateFromError",
+            "mixins",
+            "styledComponentId",
+            "componentStyle",
+            "foldedComponentIds",
+            "target",
+            "attrs",
+            "shouldForwardProp",
+            "withComponent",
+            "warnTooManyClasses",
+            "toString",
+            "__emotion_real",
+            "__emotion_base",
+            "__emotion_styles",
+            "__emotion_forwardProp"
+        ].includes(key))));
+}
+const StyledButton = styled(_hoistStatics(_forwardRef((props, ref)=><Button data-element="StyledButton" data-source-file="test.jsx" {...props} ref={ref}/>), Button));
+// @emotion/styled is not configured
+const StyledCard = emotionStyled(Card);
@@ -0,0 +1,19 @@
+import React, { Suspense } from 'react';
+import { QueryClientProvider } from '@tanstack/react-query';
+
+function App({ client }) {
+  return (
+    <QueryClientProvider client={client}>
+      <div>App</div>
+    </QueryClientProvider>
+  );
+}
+
+// Suspense is not in the custom list
+function Lazy() {
+  return (
+    <Suspense fallback={null}>
+      <div>Lazy</div>
+    </Suspense>
+  );
+}
@@ -0,0 +1,13 @@
+import React, { Suspense } from 'react';
+import { QueryClientProvider } from '@tanstack/react-query';
+function App({ client }) {
+    return <QueryClientProvider client={client}>
+      <div data-component="App" data-source-file="test.jsx">App</div>
+    </QueryClientProvider>;
+}
+// Suspense is not in the custom list
+function Lazy() {
+    return <Suspense fallback={null} data-element="Suspense" data-component="Lazy" data-source-file="test.jsx">
+      <div>Lazy</div>
+    </Suspense>;
+}
@@ -0,0 +1,7 @@
+import React, { Component } from 'react';
+
+export default class extends Component {
+  render() {
+    return <section>Profile</section>;
+  }
+}
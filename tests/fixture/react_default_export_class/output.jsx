@@ -0,0 +1,6 @@
+import React, { Component } from 'react';
+export default class extends Component {
+    render() {
+        return <section data-component="Profile" data-source-file="Profile.jsx">Profile</section>;
+    }
+}
@@ -0,0 +1,10 @@
+import React from 'react';
+
+export default function () {
+  return (
+    <main>
+      <h1>Home</h1>
+      <Hero />
+    </main>
+  );
+}
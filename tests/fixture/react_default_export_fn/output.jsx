@@ -0,0 +1,7 @@
+import React from 'react';
+export default function() {
+    return <main data-component="Home" data-source-file="Home/index.jsx">
+      <h1>Home</h1>
+      <Hero data-element="Hero" data-source-file="Home/index.jsx"/>
+    </main>;
+}
@@ -0,0 +1,8 @@
+import React, { memo } from 'react';
+
+export default memo(({ user }) => (
+  <div className="user-card">
+    <Avatar src={user.avatar} />
+    <span>{user.name}</span>
+  </div>
+));
@@ -0,0 +1,5 @@
+import React, { memo } from 'react';
+export default memo(({ user })=><div className="user-card" data-component="UserCard" data-source-file="UserCard.jsx">
+    <Avatar src={user.avatar} data-element="Avatar" data-source-file="UserCard.jsx"/>
+    <span>{user.name}</span>
+  </div>);
@@ -0,0 +1,7 @@
+import React, { forwardRef } from 'react';
+
+// Functions wrapped in a component wrapper keep their own name rather than
+// being named after the file
+export default forwardRef(function Button(props, ref) {
+  return <button ref={ref} {...props} />;
+});
@@ -0,0 +1,6 @@
+import React, { forwardRef } from 'react';
+// Functions wrapped in a component wrapper keep their own name rather than
+// being named after the file
+export default forwardRef(function Button(props, ref) {
+    return <button ref={ref} {...props} data-component="Button" data-source-file="Toolbar.jsx"/>;
+});
@@ -0,0 +1,15 @@
+import React from 'react';
+
+// Fragment destructured from the imported React
+const { Fragment, Fragment: Group } = React;
+
+export const Glossary = ({ entries }) => (
+  <Fragment>
+    {entries.map((entry) => (
+      <Group key={entry.id}>
+        <dt>{entry.term}</dt>
+        <dd>{entry.description}</dd>
+      </Group>
+    ))}
+  </Fragment>
+);
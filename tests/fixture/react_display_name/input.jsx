@@ -0,0 +1,48 @@
+import React, { Component, memo } from 'react';
+
+function Summary({ total }) {
+  return <div>{total}</div>;
+}
+Summary.displayName = 'Checkout.Summary';
+
+const Price = memo(({ value }) => <span>{value}</span>);
+Price.displayName = `Checkout.Price`;
+
+class Cart extends Component {
+  static displayName = 'Checkout.Cart';
+
+  render() {
+    return (
+      <section>
+        <Summary total={this.props.total} />
+        <Price value={this.props.total} />
+        <Unnamed />
+      </section>
+    );
+  }
+}
+
+const Menu = () => <ul />;
+Menu.Item = () => <li />;
+Menu.Item.displayName = 'MenuItem';
+
+// Components without a displayName keep their local name
+function Unnamed() {
+  return (
+    <Menu>
+      <Menu.Item />
+    </Menu>
+  );
+}
+
+// Computed display names are ignored
+function Dynamic() {
+  return <p />;
+}
+Dynamic.displayName = getName();
+
+// Local bindings shadowing a component with a displayName keep their own name
+function Receipt() {
+  const Summary = () => <b />;
+  return <Summary />;
+}
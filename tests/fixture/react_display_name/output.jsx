@@ -0,0 +1,36 @@
+import React, { Component, memo } from 'react';
+function Summary({ total }) {
+    return <div data-component="Checkout.Summary" data-source-file="test.jsx">{total}</div>;
+}
+Summary.displayName = 'Checkout.Summary';
+const Price = memo(({ value })=><span data-component="Checkout.Price" data-source-file="test.jsx">{value}</span>);
+Price.displayName = `Checkout.Price`;
+class Cart extends Component {
+    static displayName = 'Checkout.Cart';
+    render() {
+        return <section data-component="Checkout.Cart" data-source-file="test.jsx">
+        <Summary total={this.props.total} data-element="Checkout.Summary" data-source-file="test.jsx"/>
+        <Price value={this.props.total} data-element="Checkout.Price" data-source-file="test.jsx"/>
+        <Unnamed data-element="Unnamed" data-source-file="test.jsx"/>
+      </section>;
+    }
+}
+const Menu = ()=><ul data-component="Menu" data-source-file="test.jsx"/>;
+Menu.Item = ()=><li data-component="MenuItem" data-source-file="test.jsx"/>;
+Menu.Item.displayName = 'MenuItem';
+// Components without a displayName keep their local name
+function Unnamed() {
+    return <Menu data-element="Menu" data-component="Unnamed" data-source-file="test.jsx">
+      <Menu.Item data-element="MenuItem" data-source-file="test.jsx"/>
+    </Menu>;
+}
+// Computed display names are ignored
+function Dynamic() {
+    return <p data-component="Dynamic" data-source-file="test.jsx"/>;
+}
+Dynamic.displayName = getName();
+// Local bindings shadowing a component with a displayName keep their own name
+function Receipt() {
+    const Summary = ()=><b data-component="Summary" data-source-file="test.jsx"/>;
+    return <Summary data-element="Summary" data-component="Receipt" data-source-file="test.jsx"/>;
+}
@@ -0,0 +1,41 @@
+import styled from '@emotion/styled';
+import { css } from '@emotion/react';
+import { Button } from './Button';
+
+const StyledHeader = styled.header`
+  display: flex;
+`;
+
+const StyledButton = styled(Button, {
+  shouldForwardProp: (prop) => prop !== 'primary',
+})(({ primary }) => ({
+  color: primary ? 'blue' : 'black',
+}));
+
+// Options that aren't an object literal are spread into one
+const StyledLink = styled(Button, linkOptions)`
+  text-decoration: none;
+`;
+
+const titleStyles = css`
+  font-size: 24px;
+`;
+
+const layout = {
+  Sidebar: styled('aside')({
+    width: 240,
+  }),
+};
+
+export default function Page() {
+  return (
+    <StyledHeader>
+      <h1 css={titleStyles}>Title</h1>
+      <p css={css({ margin: 0 })}>Body</p>
+    </StyledHeader>
+  );
+}
+
+export const Badge = () => <span css={css`color: red`}>New</span>;
+
+export const Swatch = ({ color }) => <i css={css`color: ${color}`} />;
@@ -0,0 +1,41 @@
+import styled from '@emotion/styled';
+import { css } from '@emotion/react';
+import { Button } from './Button';
+const StyledHeader = styled("header", {
+    label: "StyledHeader"
+})`
+  display: flex;
+`;
+const StyledButton = styled(Button, {
+    shouldForwardProp: (prop)=>prop !== 'primary',
+    label: "StyledButton"
+})(({ primary })=>({
+        color: primary ? 'blue' : 'black'
+    }));
+// Options that aren't an object literal are spread into one
+const StyledLink = styled(Button, {
+    ...linkOptions,
+    label: "StyledLink"
+})`
+  text-decoration: none;
+`;
+const titleStyles = css`
+  font-size: 24px;
+label:titleStyles;`;
+const layout = {
+    Sidebar: styled('aside', {
+        label: "layout-Sidebar"
+    })({
+        width: 240
+    })
+};
+export default function Page() {
+    return <StyledHeader data-element="StyledHeader" data-component="Page" data-source-file="test.jsx">
+      <h1 css={titleStyles}>Title</h1>
+      <p css={css({
+        margin: 0
+    }, "label:Page;")}>Body</p>
+    </StyledHeader>;
+}
+export const Badge = ()=><span css={css`color: red;label:Badge;`} data-component="Badge" data-source-file="test.jsx">New</span>;
+export const Swatch = ({ color })=><i css={css`color: ${color};label:Swatch;`} data-component="Swatch" data-source-file="test.jsx"/>;
@@ -0,0 +1,16 @@
+import emotionStyled from '@emotion/styled';
+import { css as emotionCss } from '@emotion/react';
+import styled from 'styled-components';
+
+export const Card = emotionStyled.div`
+  padding: 16px;
+`;
+
+export const cardStyles = emotionCss({
+  borderRadius: 4,
+});
+
+// Only emotion's styled is labeled
+export const Panel = styled.div`
+  padding: 8px;
+`;
@@ -0,0 +1,21 @@
+import { forwardRef as _forwardRef } from "react";
+import _isPropValid from "@emotion/is-prop-valid";
+import emotionStyled from '@emotion/styled';
+import { css as emotionCss } from '@emotion/react';
+import styled from 'styled-components';
+export const Card = emotionStyled(_forwardRef((props, ref)=><div data-element="Card" data-source-file="Card.styles.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid,
+    label: "cards-Card-styles--Card"
+})`
+  padding: 16px;
+`;
+export const cardStyles = emotionCss({
+    borderRadius: 4
+}, "label:cards-Card-styles--cardStyles;");
+// Only emotion's styled is labeled
+export const Panel = styled.div.attrs({
+    "data-element": "Panel",
+    "data-source-file": "Card.styles.jsx"
+})`
+  padding: 8px;
+`;
@@ -0,0 +1,29 @@
+import React from 'react';
+
+const Table = ({ rows, loading, selected }) => {
+  return (
+    <div>
+      {rows.map((row) => (
+        <Row key={row.id}>
+          <span>{row.name}</span>
+        </Row>
+      ))}
+      {loading && <Badge>Loading</Badge>}
+      {selected ? <Selected /> : <Unselected />}
+      <Button icon={<Icon name="plus" />}>Add</Button>
+    </div>
+  );
+};
+
+const Routes = () => {
+  return (
+    <Switch>
+      <Route path="/" element={<Home />} />
+      <Route path="/about" element={<><About /></>} />
+    </Switch>
+  );
+};
+
+const Items = ({ items }) => {
+  return <>{items.map((item) => <Item key={item.id} />)}</>;
+};
@@ -0,0 +1,20 @@
+import React from 'react';
+const Table = ({ rows, loading, selected })=>{
+    return <div data-component="Table" data-source-file="test.jsx">
+      {rows.map((row)=><Row key={row.id} data-element="Row" data-source-file="test.jsx">
+          <span>{row.name}</span>
+        </Row>)}
+      {loading && <Badge data-element="Badge" data-source-file="test.jsx">Loading</Badge>}
+      {selected ? <Selected data-element="Selected" data-source-file="test.jsx"/> : <Unselected data-element="Unselected" data-source-file="test.jsx"/>}
+      <Button icon={<Icon name="plus" data-element="Icon" data-source-file="test.jsx"/>} data-element="Button" data-source-file="test.jsx">Add</Button>
+    </div>;
+};
+const Routes = ()=>{
+    return <Switch data-element="Switch" data-component="Routes" data-source-file="test.jsx">
+      <Route path="/" element={<Home data-element="Home" data-source-file="test.jsx"/>} data-element="Route" data-source-file="test.jsx"/>
+      <Route path="/about" element={<><About data-element="About" data-source-file="test.jsx"/></>} data-element="Route" data-source-file="test.jsx"/>
+    </Switch>;
+};
+const Items = ({ items })=>{
+    return <>{items.map((item)=><Item key={item.id} data-element="Item" data-source-file="test.jsx"/>)}</>;
+};
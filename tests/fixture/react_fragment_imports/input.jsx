@@ -0,0 +1,27 @@
+import { Fragment as F } from 'react';
+import * as R from 'react';
+
+function Aliased() {
+  return (
+    <F>
+      <h1>Aliased</h1>
+    </F>
+  );
+}
+
+function Namespaced() {
+  return (
+    <R.Fragment>
+      <h1>Namespaced</h1>
+    </R.Fragment>
+  );
+}
+
+// Shadowing the import makes F a regular component
+function Shadowed({ F }) {
+  return (
+    <F>
+      <h1>Shadowed</h1>
+    </F>
+  );
+}
@@ -0,0 +1,18 @@
+import { Fragment as F } from 'react';
+import * as R from 'react';
+function Aliased() {
+    return <F>
+      <h1 data-component="Aliased" data-source-file="test.jsx">Aliased</h1>
+    </F>;
+}
+function Namespaced() {
+    return <R.Fragment>
+      <h1 data-component="Namespaced" data-source-file="test.jsx">Namespaced</h1>
+    </R.Fragment>;
+}
+// Shadowing the import makes F a regular component
+function Shadowed({ F }) {
+    return <F data-element="F" data-component="Shadowed" data-source-file="test.jsx">
+      <h1>Shadowed</h1>
+    </F>;
+}
@@ -0,0 +1,18 @@
+const React = require('react');
+const { Fragment } = require('react');
+
+function Required() {
+  return (
+    <React.Fragment>
+      <h1>Required</h1>
+    </React.Fragment>
+  );
+}
+
+function Destructured() {
+  return (
+    <Fragment>
+      <h1>Destructured</h1>
+    </Fragment>
+  );
+}
@@ -0,0 +1,12 @@
+const React = require('react');
+const { Fragment } = require('react');
+function Required() {
+    return <React.Fragment>
+      <h1 data-component="Required" data-source-file="test.jsx">Required</h1>
+    </React.Fragment>;
+}
+function Destructured() {
+    return <Fragment>
+      <h1 data-component="Destructured" data-source-file="test.jsx">Destructured</h1>
+    </Fragment>;
+}
@@ -59,8 +59,8 @@ const EdgeCasesComponent = ()=>{
 };
 const ConditionalComponent = ()=>{
     return <>
-      {true && <div>Conditional content</div>}
-      {false || <span>Alternative content</span>}
+      {true && <div data-component="ConditionalComponent" data-source-file="test.jsx">Conditional content</div>}
+      {false || <span data-component="ConditionalComponent" data-source-file="test.jsx">Alternative content</span>}
     </>;
 };
-export default MyComponent; 
\ No newline at end of file
+export default MyComponent;
@@ -0,0 +1,32 @@
+// React is a global here (UMD build or webpack's ProvidePlugin)
+const List = ({ items }) => {
+  return (
+    <React.Fragment>
+      {items.map((item) => (
+        <React.Fragment key={item.id}>
+          <dt>{item.term}</dt>
+          <dd>{item.description}</dd>
+        </React.Fragment>
+      ))}
+    </React.Fragment>
+  );
+};
+
+// Fragment destructured from the global React
+const { Fragment } = React;
+
+const Terms = ({ terms }) => (
+  <Fragment>
+    {terms.map((term) => (
+      <dt key={term}>{term}</dt>
+    ))}
+  </Fragment>
+);
+
+// A local React shadowing the global is not React
+const Preview = () => {
+  const React = { Fragment: Card };
+  return <React.Fragment />;
+};
+
+export { List, Terms, Preview };
@@ -0,0 +1,10 @@
+export default function Toolbar() {
+  return (
+    <div>
+      <section>
+        <Icon />
+        <Spacer />
+      </section>
+    </div>
+  );
+}
@@ -0,0 +1,8 @@
+export default function Toolbar() {
+    return <div data-component="Toolbar" data-source-file="test.jsx">
+      <section data-element="section" data-source-file="test.jsx">
+        <Icon data-element="Icon" data-source-file="test.jsx"/>
+        <Spacer/>
+      </section>
+    </div>;
+}
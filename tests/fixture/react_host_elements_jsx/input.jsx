@@ -0,0 +1,11 @@
+export default function Dashboard() {
+  return (
+    <dashboard-shell>
+      <my-widget title="Sales" />
+      <custom>
+        <Chart />
+        <ui.Panel />
+      </custom>
+    </dashboard-shell>
+  );
+}
@@ -0,0 +1,9 @@
+export default function Dashboard() {
+    return <dashboard-shell data-component="Dashboard" data-source-file="test.jsx">
+      <my-widget title="Sales"/>
+      <custom>
+        <Chart data-element="Chart" data-source-file="test.jsx"/>
+        <ui.Panel data-element="ui.Panel" data-source-file="test.jsx"/>
+      </custom>
+    </dashboard-shell>;
+}
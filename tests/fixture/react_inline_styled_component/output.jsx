@@ -1,11 +1,42 @@
 import React from 'react';
 import styled from '@emotion/styled';
+function _hoistStatics(target, source) {
+    return Object.assign(target, Object.fromEntries(Object.entries(source).filter(([key])=>![
+            "$$typeof",
+            "render",
+            "type",
+            "compare",
+            "displayName",
+            "defaultProps",
+            "propTypes",
+            "contextType",
+            "contextTypes",
+            "childContextTypes",
+            "getDefaultProps",
+            "getDerivedStateFromProps",
+            "getDerivedStateFromError",
+            "mixins",
+            "styledComponentId",
+            "componentStyle",
+            "foldedComponentIds",
+            "target",
+            "attrs",
+            "shouldForwardProp",
+            "withComponent",
+            "warnTooManyClasses",
+            "toString",
+            "__emotion_real",
+            "__emotion_base",
+            "__emotion_styles",
+            "__emotion_forwardProp"
+        ].includes(key))));
+}
 // A regular React component
 const Button = ({ children, ...props })=>{
     return <button {...props} data-component="Button" data-source-file="test.jsx">{children}</button>;
 };
 // Styled component using component reference
-const StyledButton = styled((props)=><Button data-element="StyledButton" data-source-file="test.jsx" {...props}/>);
+const StyledButton = styled(_hoistStatics(React.forwardRef((props, ref)=><Button data-element="StyledButton" data-source-file="test.jsx" {...props} ref={ref}/>), Button));
 // Another regular component
 const Card = (props)=>{
     return <div className="card" data-component="Card" data-source-file="test.jsx">
@@ -14,7 +45,7 @@ const Card = (props)=>{
     </div>;
 };
 // Styled component using component reference
-const StyledCard = styled((props)=><Card data-element="StyledCard" data-source-file="test.jsx" {...props}/>);
+const StyledCard = styled(_hoistStatics(React.forwardRef((props, ref)=><Card data-element="StyledCard" data-source-file="test.jsx" {...props} ref={ref}/>), Card));
 // Component that uses the styled components
 const MyComponent = ()=>{
     return <div data-component="MyComponent" data-source-file="test.jsx">
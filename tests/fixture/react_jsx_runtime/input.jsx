@@ -0,0 +1,18 @@
+import { jsx as _jsx, jsxs as _jsxs, Fragment as _Fragment } from 'react/jsx-runtime';
+
+export function App({ title }) {
+  return _jsxs('div', {
+    className: 'app',
+    children: [_jsx('h1', { children: title }), _jsx(Button, { label: 'Save' })],
+  });
+}
+
+export const Layout = () => {
+  const content = _jsx(Page, {});
+  return content;
+};
+
+export const Rows = ({ rows }) =>
+  _jsxs(_Fragment, {
+    children: [_jsx('header', {}), rows.map((row) => _jsx(Row, { row }, row.id))],
+  });
@@ -0,0 +1,39 @@
+import { jsx as _jsx, jsxs as _jsxs, Fragment as _Fragment } from 'react/jsx-runtime';
+export function App({ title }) {
+    return _jsxs('div', {
+        className: 'app',
+        children: [
+            _jsx('h1', {
+                children: title
+            }),
+            _jsx(Button, {
+                label: 'Save',
+                "data-element": "Button",
+                "data-source-file": "test.jsx"
+            })
+        ],
+        "data-component": "App",
+        "data-source-file": "test.jsx"
+    });
+}
+export const Layout = ()=>{
+    const content = _jsx(Page, {
+        "data-element": "Page",
+        "data-component": "Layout",
+        "data-source-file": "test.jsx"
+    });
+    return content;
+};
+export const Rows = ({ rows })=>_jsxs(_Fragment, {
+        children: [
+            _jsx('header', {
+                "data-component": "Rows",
+                "data-source-file": "test.jsx"
+            }),
+            rows.map((row)=>_jsx(Row, {
+                    row,
+                    "data-element": "Row",
+                    "data-source-file": "test.jsx"
+                }, row.id))
+        ]
+    });
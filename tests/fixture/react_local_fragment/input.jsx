@@ -0,0 +1,14 @@
+import React from 'react';
+
+// A user component that happens to be named Fragment
+function Fragment({ children }) {
+  return <div className="fragment">{children}</div>;
+}
+
+function Page() {
+  return (
+    <Fragment>
+      <h1>Page</h1>
+    </Fragment>
+  );
+}
@@ -0,0 +1,10 @@
+import React from 'react';
+// A user component that happens to be named Fragment
+function Fragment({ children }) {
+    return <div className="fragment" data-component="Fragment" data-source-file="test.jsx">{children}</div>;
+}
+function Page() {
+    return <Fragment data-element="Fragment" data-component="Page" data-source-file="test.jsx">
+      <h1>Page</h1>
+    </Fragment>;
+}
@@ -0,0 +1,45 @@
+import React from 'react';
+
+function Layout() {
+  const content = (
+    <section>
+      <h1>Title</h1>
+    </section>
+  );
+
+  return content;
+}
+
+const Toggle = ({ open }) => {
+  let body;
+  if (open) {
+    body = <Panel />;
+  } else {
+    body = <p>Closed</p>;
+  }
+
+  return <>{body}</>;
+};
+
+function Aliased() {
+  const inner = <article>Aliased</article>;
+  const outer = inner;
+  return outer;
+}
+
+function Shadowed() {
+  const content = <section>Outer</section>;
+
+  {
+    // A different binding with the same name, not returned
+    const content = <aside>Inner</aside>;
+    console.log(content);
+  }
+
+  const log = () => {
+    const content = <footer>Callback</footer>;
+    return content;
+  };
+
+  return content;
+}
@@ -0,0 +1,34 @@
+import React from 'react';
+function Layout() {
+    const content = <section data-component="Layout" data-source-file="test.jsx">
+      <h1>Title</h1>
+    </section>;
+    return content;
+}
+const Toggle = ({ open })=>{
+    let body;
+    if (open) {
+        body = <Panel data-element="Panel" data-component="Toggle" data-source-file="test.jsx"/>;
+    } else {
+        body = <p data-component="Toggle" data-source-file="test.jsx">Closed</p>;
+    }
+    return <>{body}</>;
+};
+function Aliased() {
+    const inner = <article data-component="Aliased" data-source-file="test.jsx">Aliased</article>;
+    const outer = inner;
+    return outer;
+}
+function Shadowed() {
+    const content = <section data-component="Shadowed" data-source-file="test.jsx">Outer</section>;
+    {
+        // A different binding with the same name, not returned
+        const content = <aside>Inner</aside>;
+        console.log(content);
+    }
+    const log = ()=>{
+        const content = <footer data-component="Shadowed" data-source-file="test.jsx">Callback</footer>;
+        return content;
+    };
+    return content;
+}
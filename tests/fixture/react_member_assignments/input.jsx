@@ -0,0 +1,34 @@
+import React from 'react';
+
+function Menu({ children }) {
+  return <ul>{children}</ul>;
+}
+
+// Compound components
+Menu.Item = ({ label }) => <li>{label}</li>;
+Menu.Divider = function () {
+  return <hr />;
+};
+Menu.displayName = 'Menu';
+
+// Objects of components are named after their binding
+const parts = {
+  Header: () => <h1>Header</h1>,
+  Footer() {
+    return <footer>Footer</footer>;
+  },
+  'Sidebar': () => <aside>Sidebar</aside>,
+  renderLabel: () => <span>Label</span>,
+  forms: {
+    Input: (props) => <input {...props} />,
+  },
+};
+
+// Computed members are not named
+registry[key] = () => <div>Dynamic</div>;
+
+// CommonJS named exports are named after the export
+exports.Card = function () {
+  return <div>Card</div>;
+};
+module.exports.Badge = () => <span>Badge</span>;
@@ -0,0 +1,29 @@
+import React from 'react';
+function Menu({ children }) {
+    return <ul data-component="Menu" data-source-file="test.jsx">{children}</ul>;
+}
+// Compound components
+Menu.Item = ({ label })=><li data-component="Menu.Item" data-source-file="test.jsx">{label}</li>;
+Menu.Divider = function() {
+    return <hr data-component="Menu.Divider" data-source-file="test.jsx"/>;
+};
+Menu.displayName = 'Menu';
+// Objects of components are named after their binding
+const parts = {
+    Header: ()=><h1 data-component="parts.Header" data-source-file="test.jsx">Header</h1>,
+    Footer () {
+        return <footer data-component="parts.Footer" data-source-file="test.jsx">Footer</footer>;
+    },
+    'Sidebar': ()=><aside data-component="parts.Sidebar" data-source-file="test.jsx">Sidebar</aside>,
+    renderLabel: ()=><span>Label</span>,
+    forms: {
+        Input: (props)=><input {...props} data-component="parts.forms.Input" data-source-file="test.jsx"/>
+    }
+};
+// Computed members are not named
+registry[key] = ()=><div>Dynamic</div>;
+// CommonJS named exports are named after the export
+exports.Card = function() {
+    return <div data-component="Card" data-source-file="test.jsx">Card</div>;
+};
+module.exports.Badge = ()=><span data-component="Badge" data-source-file="test.jsx">Badge</span>;
@@ -0,0 +1,6 @@
+import React from 'react';
+
+// Named default exports use their own name, no option needed
+export default function Dashboard() {
+  return <div>Dashboard</div>;
+}
@@ -0,0 +1,5 @@
+import React from 'react';
+// Named default exports use their own name, no option needed
+export default function Dashboard() {
+    return <div data-component="Dashboard" data-source-file="test.jsx">Dashboard</div>;
+}
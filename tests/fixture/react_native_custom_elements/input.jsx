@@ -0,0 +1,14 @@
+import { View } from 'react-native';
+import { Image } from 'expo-image';
+import { LinearGradient } from 'expo-linear-gradient';
+
+export default function Hero({ source }) {
+  return (
+    <LinearGradient colors={['#000', '#fff']}>
+      <View>
+        <Image source={source} />
+        <Title />
+      </View>
+    </LinearGradient>
+  );
+}
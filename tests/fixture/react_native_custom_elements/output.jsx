@@ -0,0 +1,14 @@
+import { View } from 'react-native';
+import { Image } from 'expo-image';
+import { LinearGradient } from 'expo-linear-gradient';
+export default function Hero({ source }) {
+    return <LinearGradient colors={[
+        '#000',
+        '#fff'
+    ]} dataComponent="Hero" dataSourceFile="test.jsx">
+      <View>
+        <Image source={source} dataElement="Image" dataSourceFile="test.jsx"/>
+        <Title dataElement="Title" dataSourceFile="test.jsx"/>
+      </View>
+    </LinearGradient>;
+}
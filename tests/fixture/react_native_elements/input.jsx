@@ -0,0 +1,15 @@
+import { Image, Pressable, ScrollView, Text, View } from 'react-native';
+
+export default function ProfileCard({ user, onPress }) {
+  return (
+    <ScrollView>
+      <View>
+        <Image source={{ uri: user.avatar }} />
+        <Pressable onPress={onPress}>
+          <Text>{user.name}</Text>
+        </Pressable>
+        <Badge count={user.unread} />
+      </View>
+    </ScrollView>
+  );
+}
@@ -0,0 +1,14 @@
+import { Image, Pressable, ScrollView, Text, View } from 'react-native';
+export default function ProfileCard({ user, onPress }) {
+    return <ScrollView dataComponent="ProfileCard" dataSourceFile="test.jsx">
+      <View>
+        <Image source={{
+        uri: user.avatar
+    }}/>
+        <Pressable onPress={onPress}>
+          <Text>{user.name}</Text>
+        </Pressable>
+        <Badge count={user.unread} dataElement="Badge" dataSourceFile="test.jsx"/>
+      </View>
+    </ScrollView>;
+}
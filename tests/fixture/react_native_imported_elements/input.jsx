@@ -0,0 +1,14 @@
+import { Text as RNText, View } from 'react-native';
+import { Button, Modal } from './ui';
+
+// Primitives are matched through their react-native import, so a design
+// system's own Button and Modal are components
+export default function Checkout({ onPay }) {
+  return (
+    <View>
+      <RNText>Total</RNText>
+      <Button title="Pay" onPress={onPay} />
+      <Modal visible={false} />
+    </View>
+  );
+}
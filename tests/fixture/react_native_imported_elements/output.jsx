@@ -0,0 +1,11 @@
+import { Text as RNText, View } from 'react-native';
+import { Button, Modal } from './ui';
+// Primitives are matched through their react-native import, so a design
+// system's own Button and Modal are components
+export default function Checkout({ onPay }) {
+    return <View dataComponent="Checkout" dataSourceFile="test.jsx">
+      <RNText>Total</RNText>
+      <Button title="Pay" onPress={onPay} dataElement="Button" dataSourceFile="test.jsx"/>
+      <Modal visible={false} dataElement="Modal" dataSourceFile="test.jsx"/>
+    </View>;
+}
@@ -0,0 +1,41 @@
+import React, { Component } from 'react';
+
+function Outer() {
+  function Inner() {
+    return <span>Inner</span>;
+  }
+
+  const Helper = () => <em>Helper</em>;
+
+  const content = (
+    <section>
+      <Inner />
+      <Helper />
+    </section>
+  );
+
+  return content;
+}
+
+const List = ({ items }) => {
+  return (
+    <>
+      {items.map((item) => {
+        const Row = () => <li>{item}</li>;
+        return <Row key={item} />;
+      })}
+      <footer>End of list</footer>
+    </>
+  );
+};
+
+class Page extends Component {
+  render() {
+    const Title = () => <h1>Title</h1>;
+    return (
+      <main>
+        <Title />
+      </main>
+    );
+  }
+}
@@ -0,0 +1,29 @@
+import React, { Component } from 'react';
+function Outer() {
+    function Inner() {
+        return <span data-component="Inner" data-source-file="test.jsx">Inner</span>;
+    }
+    const Helper = ()=><em data-component="Helper" data-source-file="test.jsx">Helper</em>;
+    const content = <section data-component="Outer" data-source-file="test.jsx">
+      <Inner data-element="Inner" data-source-file="test.jsx"/>
+      <Helper data-element="Helper" data-source-file="test.jsx"/>
+    </section>;
+    return content;
+}
+const List = ({ items })=>{
+    return <>
+      {items.map((item)=>{
+        const Row = ()=><li data-component="Row" data-source-file="test.jsx">{item}</li>;
+        return <Row key={item} data-element="Row" data-source-file="test.jsx"/>;
+    })}
+      <footer data-component="List" data-source-file="test.jsx">End of list</footer>
+    </>;
+};
+class Page extends Component {
+    render() {
+        const Title = ()=><h1 data-component="Title" data-source-file="test.jsx">Title</h1>;
+        return <main data-component="Page" data-source-file="test.jsx">
+        <Title data-element="Title" data-source-file="test.jsx"/>
+      </main>;
+    }
+}
@@ -0,0 +1,52 @@
+import React from 'react';
+
+function Page({ loading, error }) {
+  if (loading) return <Spinner />;
+
+  if (error) {
+    return <div className="error">{error.message}</div>;
+  } else if (!error) {
+    // fall through
+  }
+
+  return <main>Page content</main>;
+}
+
+const Status = ({ status }) => {
+  switch (status) {
+    case 'ok':
+      return <span>OK</span>;
+    case 'warn': {
+      return <Warning />;
+    }
+    default:
+      return <span>Unknown</span>;
+  }
+};
+
+function SafeWidget() {
+  try {
+    return <Widget />;
+  } catch (e) {
+    return <p>Failed to render</p>;
+  }
+}
+
+function Labeled({ items }) {
+  outer: {
+    for (const item of items) {
+      if (item.featured) return <article>{item.title}</article>;
+    }
+    break outer;
+  }
+  return <div>No featured items</div>;
+}
+
+function List({ items }) {
+  // Returns inside nested functions belong to those functions
+  function renderItem(item) {
+    return <li>{item}</li>;
+  }
+
+  return <ul>{items.map(renderItem)}</ul>;
+}
@@ -0,0 +1,45 @@
+import React from 'react';
+function Page({ loading, error }) {
+    if (loading) return <Spinner data-element="Spinner" data-component="Page" data-source-file="test.jsx"/>;
+    if (error) {
+        return <div className="error" data-component="Page" data-source-file="test.jsx">{error.message}</div>;
+    } else if (!error) {
+    // fall through
+    }
+    return <main data-component="Page" data-source-file="test.jsx">Page content</main>;
+}
+const Status = ({ status })=>{
+    switch(status){
+        case 'ok':
+            return <span data-component="Status" data-source-file="test.jsx">OK</span>;
+        case 'warn':
+            {
+                return <Warning data-element="Warning" data-component="Status" data-source-file="test.jsx"/>;
+            }
+        default:
+            return <span data-component="Status" data-source-file="test.jsx">Unknown</span>;
+    }
+};
+function SafeWidget() {
+    try {
+        return <Widget data-element="Widget" data-component="SafeWidget" data-source-file="test.jsx"/>;
+    } catch (e) {
+        return <p data-component="SafeWidget" data-source-file="test.jsx">Failed to render</p>;
+    }
+}
+function Labeled({ items }) {
+    outer: {
+        for (const item of items){
+            if (item.featured) return <article data-component="Labeled" data-source-file="test.jsx">{item.title}</article>;
+        }
+        break outer;
+    }
+    return <div data-component="Labeled" data-source-file="test.jsx">No featured items</div>;
+}
+function List({ items }) {
+    // Returns inside nested functions belong to those functions
+    function renderItem(item) {
+        return <li data-component="List" data-source-file="test.jsx">{item}</li>;
+    }
+    return <ul data-component="List" data-source-file="test.jsx">{items.map(renderItem)}</ul>;
+}
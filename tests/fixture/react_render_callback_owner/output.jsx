@@ -0,0 +1,27 @@
+import React, { useCallback, useMemo } from 'react';
+function Dashboard({ data, rows }) {
+    const chart = useMemo(()=><Chart data={data} data-element="Chart" data-component="Dashboard" data-source-file="test.jsx"/>, [
+        data
+    ]);
+    const renderRow = useCallback((row)=>{
+        const cells = <td>{row.label}</td>;
+        return <tr data-component="Dashboard" data-source-file="test.jsx">{cells}</tr>;
+    }, []);
+    return <div data-component="Dashboard" data-source-file="test.jsx">
+      {chart}
+      <List items={rows} renderItem={(item)=><Item item={item} data-element="Item" data-component="Dashboard" data-source-file="test.jsx"/>} data-element="List" data-source-file="test.jsx"/>
+      <Query data-element="Query" data-source-file="test.jsx">
+        {(result)=><>
+            <span data-component="Dashboard" data-source-file="test.jsx">{result.count}</span>
+            <Table rows={result.rows} data-element="Table" data-component="Dashboard" data-source-file="test.jsx"/>
+          </>}
+      </Query>
+      <Button onClick={()=>track('click')} data-element="Button" data-source-file="test.jsx"/>
+    </div>;
+}
+// Callbacks outside of components are left alone
+const legacy = useMemo(()=><Chart data-element="Chart" data-source-file="test.jsx"/>, []);
+// Callbacks of ignored components are ignored too
+function IgnoredOwner() {
+    return <List renderItem={()=><Item/>}/>;
+}
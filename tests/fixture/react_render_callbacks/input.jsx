@@ -0,0 +1,34 @@
+import React, { useCallback, useMemo } from 'react';
+
+function Dashboard({ data, rows }) {
+  const chart = useMemo(() => <Chart data={data} />, [data]);
+
+  const renderRow = useCallback((row) => {
+    const cells = <td>{row.label}</td>;
+    return <tr>{cells}</tr>;
+  }, []);
+
+  return (
+    <div>
+      {chart}
+      <List items={rows} renderItem={(item) => <Item item={item} />} />
+      <Query>
+        {(result) => (
+          <>
+            <span>{result.count}</span>
+            <Table rows={result.rows} />
+          </>
+        )}
+      </Query>
+      <Button onClick={() => track('click')} />
+    </div>
+  );
+}
+
+// Callbacks outside of components are left alone
+const legacy = useMemo(() => <Chart />, []);
+
+// Callbacks of ignored components are ignored too
+function IgnoredOwner() {
+  return <List renderItem={() => <Item />} />;
+}
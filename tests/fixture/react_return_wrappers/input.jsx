@@ -0,0 +1,15 @@
+import React from 'react';
+
+const Modal = ({ isOpen }) => {
+  return isOpen && <div className="modal">Modal</div>;
+};
+
+function Greeting({ user }) {
+  return user ?? <Login />;
+}
+
+function Fallback({ content }) {
+  return content || <p>Nothing to show</p>;
+}
+
+const Logged = () => (console.log('render'), <span>Logged</span>);
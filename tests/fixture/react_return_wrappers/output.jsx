@@ -0,0 +1,11 @@
+import React from 'react';
+const Modal = ({ isOpen })=>{
+    return isOpen && <div className="modal" data-component="Modal" data-source-file="test.jsx">Modal</div>;
+};
+function Greeting({ user }) {
+    return user ?? <Login data-element="Login" data-component="Greeting" data-source-file="test.jsx"/>;
+}
+function Fallback({ content }) {
+    return content || <p data-component="Fallback" data-source-file="test.jsx">Nothing to show</p>;
+}
+const Logged = ()=>(console.log('render'), <span data-component="Logged" data-source-file="test.jsx">Logged</span>);
@@ -0,0 +1,28 @@
+import styled from 'styled-components';
+
+const StyledNav = styled.nav`
+  display: flex;
+`;
+
+const StyledLink = styled('a')(({ $active }) => ({
+  fontWeight: $active ? 'bold' : 'normal',
+}));
+
+const StyledInput = styled.input.attrs({ type: 'search' })`
+  border: 0;
+`;
+
+const Navigation = ({ links }) => {
+  return (
+    <StyledNav as="header" isSticky>
+      {links.map((link) => (
+        <StyledLink as="button" key={link.href} $active={link.active}>
+          {link.label}
+        </StyledLink>
+      ))}
+      <StyledInput placeholder="Search" />
+    </StyledNav>
+  );
+};
+
+export default Navigation;
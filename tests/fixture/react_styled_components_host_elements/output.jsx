@@ -0,0 +1,30 @@
+import styled from 'styled-components';
+const StyledNav = styled.nav.attrs({
+    "data-element": "StyledNav",
+    "data-source-file": "test.jsx"
+})`
+  display: flex;
+`;
+const StyledLink = styled('a').attrs({
+    "data-element": "StyledLink",
+    "data-source-file": "test.jsx"
+})(({ $active })=>({
+        fontWeight: $active ? 'bold' : 'normal'
+    }));
+const StyledInput = styled.input.attrs({
+    "data-element": "StyledInput",
+    "data-source-file": "test.jsx"
+}).attrs({
+    type: 'search'
+})`
+  border: 0;
+`;
+const Navigation = ({ links })=>{
+    return <StyledNav as="header" isSticky data-element="StyledNav" data-component="Navigation" data-source-file="test.jsx">
+      {links.map((link)=><StyledLink as="button" key={link.href} $active={link.active} data-element="StyledLink" data-source-file="test.jsx">
+          {link.label}
+        </StyledLink>)}
+      <StyledInput placeholder="Search" data-element="StyledInput" data-source-file="test.jsx"/>
+    </StyledNav>;
+};
+export default Navigation;
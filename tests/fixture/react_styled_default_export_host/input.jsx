@@ -0,0 +1,5 @@
+import styled from 'styled-components';
+
+export default styled.section`
+  display: grid;
+`;
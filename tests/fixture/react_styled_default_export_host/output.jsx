@@ -0,0 +1,7 @@
+import styled from 'styled-components';
+export default styled.section.attrs({
+    "data-element": "PageGrid",
+    "data-source-file": "PageGrid.jsx"
+})`
+  display: grid;
+`;
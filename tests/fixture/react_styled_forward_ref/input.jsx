@@ -0,0 +1,35 @@
+import { forwardRef as reactForwardRef, useRef } from 'react';
+import styled from '@emotion/styled';
+import { Menu, Tooltip } from './ui';
+
+// Statics such as Menu.Item are copied onto the wrapper, for libraries that
+// hoist them onto the styled component (emotion doesn't)
+const StyledMenu = styled(Menu)`
+  padding: 4px;
+`;
+
+// Tooltip is ignored and left as is
+const StyledTooltip = styled(Tooltip)`
+  z-index: 10;
+`;
+
+const StyledInput = styled('input', {
+  shouldForwardProp: (prop) => prop !== 'invalid',
+})(({ invalid }) => ({
+  borderColor: invalid ? 'red' : 'gray',
+}));
+
+const StyledTextArea = StyledInput.withComponent('textarea');
+
+const Field = () => {
+  const ref = useRef(null);
+  return (
+    <StyledMenu>
+      <Menu.Item>
+        <StyledInput ref={ref} invalid />
+      </Menu.Item>
+    </StyledMenu>
+  );
+};
+
+export default Field;
@@ -0,0 +1,58 @@
+import { forwardRef as reactForwardRef, useRef } from 'react';
+import styled from '@emotion/styled';
+import { Menu, Tooltip } from './ui';
+function _hoistStatics(target, source) {
+    return Object.assign(target, Object.fromEntries(Object.entries(source).filter(([key])=>![
+            "$$typeof",
+            "render",
+            "type",
+            "compare",
+            "displayName",
+            "defaultProps",
+            "propTypes",
+            "contextType",
+            "contextTypes",
+            "childContextTypes",
+            "getDefaultProps",
+            "getDerivedStateFromProps",
+            "getDerivedStateFromError",
+            "mixins",
+            "styledComponentId",
+            "componentStyle",
+            "foldedComponentIds",
+            "target",
+            "attrs",
+            "shouldForwardProp",
+            "withComponent",
+            "warnTooManyClasses",
+            "toString",
+            "__emotion_real",
+            "__emotion_base",
+            "__emotion_styles",
+            "__emotion_forwardProp"
+        ].includes(key))));
+}
+// Statics such as Menu.Item are copied onto the wrapper, for libraries that
+// hoist them onto the styled component (emotion doesn't)
+const StyledMenu = styled(_hoistStatics(reactForwardRef((props, ref)=><Menu data-element="StyledMenu" data-source-file="test.jsx" {...props} ref={ref}/>), Menu))`
+  padding: 4px;
+`;
+// Tooltip is ignored and left as is
+const StyledTooltip = styled(Tooltip)`
+  z-index: 10;
+`;
+const StyledInput = styled(reactForwardRef((props, ref)=><input data-element="StyledInput" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: (prop)=>prop !== 'invalid'
+})(({ invalid })=>({
+        borderColor: invalid ? 'red' : 'gray'
+    }));
+const StyledTextArea = StyledInput.withComponent('textarea');
+const Field = ()=>{
+    const ref = useRef(null);
+    return <StyledMenu data-element="StyledMenu" data-component="Field" data-source-file="test.jsx">
+      <Menu.Item data-element="Menu.Item" data-source-file="test.jsx">
+        <StyledInput ref={ref} invalid data-element="StyledInput" data-source-file="test.jsx"/>
+      </Menu.Item>
+    </StyledMenu>;
+};
+export default Field;
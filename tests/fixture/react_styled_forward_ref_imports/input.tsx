@@ -0,0 +1,13 @@
+import type React from 'react';
+import { type forwardRef } from 'react';
+import * as runtime from 'react/jsx-runtime';
+import styled from '@emotion/styled';
+
+// Type-only imports are erased and the JSX runtime has no forwardRef, so
+// styled wrappers import their own
+export const StyledCard = styled(Card)`
+  padding: 16px;
+`;
+
+export type CardRef = React.ElementRef<typeof forwardRef>;
+export const element = runtime.jsx('div', {});
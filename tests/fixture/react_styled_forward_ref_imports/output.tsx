@@ -0,0 +1,43 @@
+import { forwardRef as _forwardRef } from "react";
+import type React from 'react';
+import { type forwardRef } from 'react';
+import * as runtime from 'react/jsx-runtime';
+import styled from '@emotion/styled';
+function _hoistStatics(target, source) {
+    return Object.assign(target, Object.fromEntries(Object.entries(source).filter(([key])=>![
+            "$$typeof",
+            "render",
+            "type",
+            "compare",
+            "displayName",
+            "defaultProps",
+            "propTypes",
+            "contextType",
+            "contextTypes",
+            "childContextTypes",
+            "getDefaultProps",
+            "getDerivedStateFromProps",
+            "getDerivedStateFromError",
+            "mixins",
+            "styledComponentId",
+            "componentStyle",
+            "foldedComponentIds",
+            "target",
+            "attrs",
+            "shouldForwardProp",
+            "withComponent",
+            "warnTooManyClasses",
+            "toString",
+            "__emotion_real",
+            "__emotion_base",
+            "__emotion_styles",
+            "__emotion_forwardProp"
+        ].includes(key))));
+}
+// Type-only imports are erased and the JSX runtime has no forwardRef, so
+// styled wrappers import their own
+export const StyledCard = styled(_hoistStatics(_forwardRef((props, ref)=><Card data-element="StyledCard" data-source-file="test.jsx" {...props} ref={ref}/>), Card))`
+  padding: 16px;
+`;
+export type CardRef = React.ElementRef<typeof forwardRef>;
+export const element = runtime.jsx('div', {});
@@ -0,0 +1,56 @@
+import styled from '@emotion/styled';
+import { Button } from './Button';
+
+// Host elements are rendered through a wrapper which emotion only passes
+// valid DOM attributes to, so `as` and non-DOM props keep working
+const StyledHeader = styled.header`
+  display: flex;
+`;
+
+const StyledAction = styled.button({
+  padding: 8,
+});
+
+const StyledLabel = styled('span')`
+  font-weight: bold;
+`;
+
+const StyledBadge = styled('span', { label: 'Badge' })`
+  color: red;
+`;
+
+// Options of their own are kept, taking precedence
+const StyledField = styled('input', {
+  shouldForwardProp: (prop) => prop !== 'invalid',
+})`
+  border: 1px solid;
+`;
+
+const StyledNote = styled('p', noteOptions)`
+  font-style: italic;
+`;
+
+const StyledInput = styled.input.attrs({ type: 'text' })`
+  border: 0;
+`;
+
+const StyledButton = styled(Button)(({ primary }) => ({
+  color: primary ? 'blue' : 'black',
+}));
+
+const StyledLink = styled(Button, {
+  shouldForwardProp: (prop) => prop !== 'active',
+})`
+  text-decoration: none;
+`;
+
+const Toolbar = () => {
+  return (
+    <StyledHeader>
+      <StyledLabel>Title</StyledLabel>
+      <StyledAction as="a" href="/save" isPrimary>Save</StyledAction>
+    </StyledHeader>
+  );
+};
+
+export default Toolbar;
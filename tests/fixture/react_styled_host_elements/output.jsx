@@ -0,0 +1,92 @@
+import { forwardRef as _forwardRef } from "react";
+import _isPropValid from "@emotion/is-prop-valid";
+import styled from '@emotion/styled';
+import { Button } from './Button';
+function _hoistStatics(target, source) {
+    return Object.assign(target, Object.fromEntries(Object.entries(source).filter(([key])=>![
+            "$$typeof",
+            "render",
+            "type",
+            "compare",
+            "displayName",
+            "defaultProps",
+            "propTypes",
+            "contextType",
+            "contextTypes",
+            "childContextTypes",
+            "getDefaultProps",
+            "getDerivedStateFromProps",
+            "getDerivedStateFromError",
+            "mixins",
+            "styledComponentId",
+            "componentStyle",
+            "foldedComponentIds",
+            "target",
+            "attrs",
+            "shouldForwardProp",
+            "withComponent",
+            "warnTooManyClasses",
+            "toString",
+            "__emotion_real",
+            "__emotion_base",
+            "__emotion_styles",
+            "__emotion_forwardProp"
+        ].includes(key))));
+}
+// Host elements are rendered through a wrapper which emotion only passes
+// valid DOM attributes to, so `as` and non-DOM props keep working
+const StyledHeader = styled(_forwardRef((props, ref)=><header data-element="StyledHeader" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid
+})`
+  display: flex;
+`;
+const StyledAction = styled(_forwardRef((props, ref)=><button data-element="StyledAction" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid
+})({
+    padding: 8
+});
+const StyledLabel = styled(_forwardRef((props, ref)=><span data-element="StyledLabel" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid
+})`
+  font-weight: bold;
+`;
+const StyledBadge = styled(_forwardRef((props, ref)=><span data-element="StyledBadge" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid,
+    label: 'Badge'
+})`
+  color: red;
+`;
+// Options of their own are kept, taking precedence
+const StyledField = styled(_forwardRef((props, ref)=><input data-element="StyledField" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: (prop)=>prop !== 'invalid'
+})`
+  border: 1px solid;
+`;
+const StyledNote = styled(_forwardRef((props, ref)=><p data-element="StyledNote" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid,
+    ...noteOptions
+})`
+  font-style: italic;
+`;
+const StyledInput = styled(_forwardRef((props, ref)=><input data-element="StyledInput" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid
+}).attrs({
+    type: 'text'
+})`
+  border: 0;
+`;
+const StyledButton = styled(_hoistStatics(_forwardRef((props, ref)=><Button data-element="StyledButton" data-source-file="test.jsx" {...props} ref={ref}/>), Button))(({ primary })=>({
+        color: primary ? 'blue' : 'black'
+    }));
+const StyledLink = styled(_hoistStatics(_forwardRef((props, ref)=><Button data-element="StyledLink" data-source-file="test.jsx" {...props} ref={ref}/>), Button), {
+    shouldForwardProp: (prop)=>prop !== 'active'
+})`
+  text-decoration: none;
+`;
+const Toolbar = ()=>{
+    return <StyledHeader data-element="StyledHeader" data-component="Toolbar" data-source-file="test.jsx">
+      <StyledLabel data-element="StyledLabel" data-source-file="test.jsx">Title</StyledLabel>
+      <StyledAction as="a" href="/save" isPrimary data-element="StyledAction" data-source-file="test.jsx">Save</StyledAction>
+    </StyledHeader>;
+};
+export default Toolbar;
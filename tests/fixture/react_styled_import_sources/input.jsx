@@ -0,0 +1,30 @@
+import styled from 'styled-components';
+import { styled as muiStyled } from '@mui/material/styles';
+import { styled as stitches } from '@stitches/react';
+import { styled as gooberStyled } from 'goober';
+import { Button, Card, Link, Avatar } from './ui';
+
+const StyledButton = styled(Button);
+
+const StyledCard = muiStyled(Card);
+
+const StyledLink = stitches(Link, {
+  color: '$primary',
+});
+
+const StyledAvatar = gooberStyled(Avatar);
+
+// Host elements: through emotion's options with MUI, a plain wrapper with
+// Stitches, and left as is with goober, which only applies `as` and its prop
+// filter to host elements
+const StyledPaper = muiStyled('div', { name: 'Paper' })({
+  padding: 8,
+});
+
+const StyledBox = stitches('section', {
+  display: 'grid',
+});
+
+const StyledList = gooberStyled('ul')`
+  margin: 0;
+`;
@@ -0,0 +1,59 @@
+import { forwardRef as _forwardRef } from "react";
+import _isPropValid from "@emotion/is-prop-valid";
+import styled from 'styled-components';
+import { styled as muiStyled } from '@mui/material/styles';
+import { styled as stitches } from '@stitches/react';
+import { styled as gooberStyled } from 'goober';
+import { Button, Card, Link, Avatar } from './ui';
+function _hoistStatics(target, source) {
+    return Object.assign(target, Object.fromEntries(Object.entries(source).filter(([key])=>![
+            "$$typeof",
+            "render",
+            "type",
+            "compare",
+            "displayName",
+            "defaultProps",
+            "propTypes",
+            "contextType",
+            "contextTypes",
+            "childContextTypes",
+            "getDefaultProps",
+            "getDerivedStateFromProps",
+            "getDerivedStateFromError",
+            "mixins",
+            "styledComponentId",
+            "componentStyle",
+            "foldedComponentIds",
+            "target",
+            "attrs",
+            "shouldForwardProp",
+            "withComponent",
+            "warnTooManyClasses",
+            "toString",
+            "__emotion_real",
+            "__emotion_base",
+            "__emotion_styles",
+            "__emotion_forwardProp"
+        ].includes(key))));
+}
+const StyledButton = styled(_hoistStatics(_forwardRef((props, ref)=><Button data-element="StyledButton" data-source-file="test.jsx" {...props} ref={ref}/>), Button));
+const StyledCard = muiStyled(_hoistStatics(_forwardRef((props, ref)=><Card data-element="StyledCard" data-source-file="test.jsx" {...props} ref={ref}/>), Card));
+const StyledLink = stitches(_hoistStatics(_forwardRef((props, ref)=><Link data-element="StyledLink" data-source-file="test.jsx" {...props} ref={ref}/>), Link), {
+    color: '$primary'
+});
+const StyledAvatar = gooberStyled(_hoistStatics(_forwardRef((props, ref)=><Avatar data-element="StyledAvatar" data-source-file="test.jsx" {...props} ref={ref}/>), Avatar));
+// Host elements: through emotion's options with MUI, a plain wrapper with
+// Stitches, and left as is with goober, which only applies `as` and its prop
+// filter to host elements
+const StyledPaper = muiStyled(_forwardRef((props, ref)=><div data-element="StyledPaper" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    shouldForwardProp: _isPropValid,
+    name: 'Paper'
+})({
+    padding: 8
+});
+const StyledBox = stitches(_forwardRef((props, ref)=><section data-element="StyledBox" data-source-file="test.jsx" {...props} ref={ref}/>), {
+    display: 'grid'
+});
+const StyledList = gooberStyled('ul')`
+  margin: 0;
+`;
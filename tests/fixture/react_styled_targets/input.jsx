@@ -0,0 +1,25 @@
+import { memo } from 'react';
+import styled from '@emotion/styled';
+import * as UI from './ui';
+import { Card } from './Card';
+
+const StyledButton = styled(UI.Button)`
+  margin: 0;
+`;
+
+const StyledCard = styled(memo(Card))({
+  padding: 16,
+});
+
+export const Layout = {
+  Header: styled.header`
+    height: 64px;
+  `,
+  Sidebar: styled(UI.Panel)`
+    width: 240px;
+  `,
+};
+
+export default styled(Card)`
+  border: 1px solid;
+`;
@@ -0,0 +1,56 @@
+import { forwardRef as _forwardRef } from "react";
+import _isPropValid from "@emotion/is-prop-valid";
+import { memo } from 'react';
+import styled from '@emotion/styled';
+import * as UI from './ui';
+import { Card } from './Card';
+function _hoistStatics(target, source) {
+    return Object.assign(target, Object.fromEntries(Object.entries(source).filter(([key])=>![
+            "$$typeof",
+            "render",
+            "type",
+            "compare",
+            "displayName",
+            "defaultProps",
+            "propTypes",
+            "contextType",
+            "contextTypes",
+            "childContextTypes",
+            "getDefaultProps",
+            "getDerivedStateFromProps",
+            "getDerivedStateFromError",
+            "mixins",
+            "styledComponentId",
+            "componentStyle",
+            "foldedComponentIds",
+            "target",
+            "attrs",
+            "shouldForwardProp",
+            "withComponent",
+            "warnTooManyClasses",
+            "toString",
+            "__emotion_real",
+            "__emotion_base",
+            "__emotion_styles",
+            "__emotion_forwardProp"
+        ].includes(key))));
+}
+const StyledButton = styled(_hoistStatics(_forwardRef((props, ref)=><UI.Button data-element="StyledButton" data-source-file="test.jsx" {...props} ref={ref}/>), UI.Button))`
+  margin: 0;
+`;
+const StyledCard = styled(((Component)=>_hoistStatics(_forwardRef((props, ref)=><Component data-element="StyledCard" data-source-file="test.jsx" {...props} ref={ref}/>), Component))(memo(Card)))({
+    padding: 16
+});
+export const Layout = {
+    Header: styled(_forwardRef((props, ref)=><header data-element="Layout.Header" data-source-file="test.jsx" {...props} ref={ref}/>), {
+        shouldForwardProp: _isPropValid
+    })`
+    height: 64px;
+  `,
+    Sidebar: styled(_hoistStatics(_forwardRef((props, ref)=><UI.Panel data-element="Layout.Sidebar" data-source-file="test.jsx" {...props} ref={ref}/>), UI.Panel))`
+    width: 240px;
+  `
+};
+export default styled(_hoistStatics(_forwardRef((props, ref)=><Card data-element="Card" data-source-file="test.jsx" {...props} ref={ref}/>), Card))`
+  border: 1px solid;
+`;
@@ -0,0 +1,17 @@
+export default function CheckIcon() {
+    return <svg viewBox="0 0 24 24" width="24" height="24" data-component="CheckIcon" data-source-file="test.jsx">
+      <g fill="none" stroke="currentColor">
+        <circle cx="12" cy="12" r="10"/>
+        <path d="M7 12l3 3 7-7"/>
+      </g>
+    </svg>;
+}
+export function Formula() {
+    return <math data-component="Formula" data-source-file="test.jsx">
+      <mrow>
+        <mi>x</mi>
+        <mo>=</mo>
+        <mn>2</mn>
+      </mrow>
+    </math>;
+}
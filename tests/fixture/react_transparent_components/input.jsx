@@ -0,0 +1,46 @@
+import React, { Suspense, StrictMode, Profiler } from 'react';
+import { BrowserRouter } from 'react-router-dom';
+
+const ThemeContext = React.createContext('light');
+
+function App({ children }) {
+  return (
+    <StrictMode>
+      <ThemeContext.Provider value="dark">
+        <BrowserRouter>
+          <Suspense fallback={<Spinner />}>
+            <main>{children}</main>
+          </Suspense>
+        </BrowserRouter>
+      </ThemeContext.Provider>
+    </StrictMode>
+  );
+}
+
+function ThemedButton() {
+  return (
+    <ThemeContext.Consumer>
+      {(theme) => <button className={theme}>Click</button>}
+    </ThemeContext.Consumer>
+  );
+}
+
+function Profiled({ onRender }) {
+  const content = <section>Profiled</section>;
+  return (
+    <Profiler id="profiled" onRender={onRender}>
+      {content}
+    </Profiler>
+  );
+}
+
+function Guarded() {
+  return (
+    <React.Suspense fallback={null}>
+      <ErrorBoundary>
+        <Page />
+        <Sidebar />
+      </ErrorBoundary>
+    </React.Suspense>
+  );
+}
@@ -0,0 +1,33 @@
+import React, { Suspense, StrictMode, Profiler } from 'react';
+import { BrowserRouter } from 'react-router-dom';
+const ThemeContext = React.createContext('light');
+function App({ children }) {
+    return <StrictMode>
+      <ThemeContext.Provider value="dark">
+        <BrowserRouter>
+          <Suspense fallback={<Spinner data-element="Spinner" data-source-file="test.jsx"/>}>
+            <main data-component="App" data-source-file="test.jsx">{children}</main>
+          </Suspense>
+        </BrowserRouter>
+      </ThemeContext.Provider>
+    </StrictMode>;
+}
+function ThemedButton() {
+    return <ThemeContext.Consumer>
+      {(theme)=><button className={theme} data-source-file="test.jsx">Click</button>}
+    </ThemeContext.Consumer>;
+}
+function Profiled({ onRender }) {
+    const content = <section data-component="Profiled" data-source-file="test.jsx">Profiled</section>;
+    return <Profiler id="profiled" onRender={onRender}>
+      {content}
+    </Profiler>;
+}
+function Guarded() {
+    return <React.Suspense fallback={null}>
+      <ErrorBoundary>
+        <Page data-element="Page" data-component="Guarded" data-source-file="test.jsx"/>
+        <Sidebar data-element="Sidebar" data-component="Guarded" data-source-file="test.jsx"/>
+      </ErrorBoundary>
+    </React.Suspense>;
+}
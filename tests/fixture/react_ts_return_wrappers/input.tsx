@@ -0,0 +1,20 @@
+import React, { ReactNode } from 'react';
+
+function List<T>({ items }: { items: T[] }) {
+  return (<ul>{items.length}</ul>) as JSX.Element;
+}
+
+const Card = (): ReactNode => {
+  return <div className="card">Card</div> satisfies ReactNode;
+};
+
+function Banner() {
+  const banner = <section>Banner</section>;
+  return banner!;
+}
+
+const Frozen = () => <article>Frozen</article> as const;
+
+const Modal = ({ isOpen }: { isOpen: boolean }) => {
+  return (isOpen && <Dialog>Open</Dialog>) as ReactNode;
+};
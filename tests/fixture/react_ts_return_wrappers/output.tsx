@@ -0,0 +1,19 @@
+import React, { ReactNode } from 'react';
+function List<T>({ items }: {
+    items: T[];
+}) {
+    return <ul data-component="List" data-source-file="test.jsx">{items.length}</ul> as JSX.Element;
+}
+const Card = (): ReactNode =>{
+    return <div className="card" data-component="Card" data-source-file="test.jsx">Card</div> satisfies ReactNode;
+};
+function Banner() {
+    const banner = <section data-component="Banner" data-source-file="test.jsx">Banner</section>;
+    return banner!;
+}
+const Frozen = ()=><article data-component="Frozen" data-source-file="test.jsx">Frozen</article> as const;
+const Modal = ({ isOpen }: {
+    isOpen: boolean;
+})=>{
+    return isOpen && <Dialog data-element="Dialog" data-component="Modal" data-source-file="test.jsx">Open</Dialog> as ReactNode;
+};
@@ -3,7 +3,7 @@ use swc_core::{
     common::{FileName, Mark},
     ecma::{
         ast::Pass,
-        parser::{EsSyntax, Syntax},
+        parser::{EsSyntax, Syntax, TsSyntax},
         transforms::{
             base::resolver,
             testing::{test, test_fixture},
@@ -11,15 +11,25 @@ use swc_core::{
         visit::visit_mut_pass,
     },
 };
-use swc_plugin_component_annotate::{config::PluginConfig, ReactComponentAnnotateVisitor};
+use swc_plugin_component_annotate::{
+    config::{ComponentAttrTarget, ComponentDetection, HostElements, PluginConfig},
+    ReactComponentAnnotateVisitor,
+};
 
-fn tr_with_config_and_filename(config: PluginConfig, filename: FileName) -> impl Pass {
+fn tr_with_config_and_filename(
+    config: PluginConfig,
+    filename: FileName,
+    typescript: bool,
+) -> impl Pass {
     let unresolved_mark = Mark::new();
     let top_level_mark = Mark::new();
 
     (
-        resolver(unresolved_mark, top_level_mark, false),
-        visit_mut_pass(ReactComponentAnnotateVisitor::new(config, &filename)),
+        resolver(unresolved_mark, top_level_mark, typescript),
+        visit_mut_pass(
+            ReactComponentAnnotateVisitor::new(config, &filename)
+                .with_unresolved_mark(unresolved_mark),
+        ),
     )
 }
 
@@ -99,78 +109,272 @@ fn test_extract_filename() {
     );
 }
 
-#[testing::fixture("tests/fixture/react_*/input.jsx")]
-fn test(input: PathBuf) {
-    let dir = input.parent().unwrap().to_path_buf();
-    let jsx = input.extension().unwrap() == "jsx";
-    let output = if jsx {
-        dir.join("output.jsx")
-    } else {
-        dir.join("output.js")
-    };
+#[test]
+fn test_extract_file_stem_and_dirname() {
+    use swc_plugin_component_annotate::path_utils::{extract_dirname, extract_file_stem};
+
+    let filename = FileName::Custom("src/components/Button.test.tsx".to_string());
+    assert_eq!(
+        extract_file_stem(&filename),
+        Some("Button.test".to_string())
+    );
+    assert_eq!(extract_dirname(&filename), Some("components".to_string()));
+
+    let filename = FileName::Custom("components\\Header\\index.jsx".to_string());
+    assert_eq!(extract_file_stem(&filename), Some("index".to_string()));
+    assert_eq!(extract_dirname(&filename), Some("Header".to_string()));
 
-    // Check if this is the sentry attrs test
-    let is_sentry_test = dir.file_name().unwrap().to_str().unwrap() == "react_sentry_attrs";
-    let is_index_test = dir.file_name().unwrap().to_str().unwrap() == "react_index_file";
-    let is_ignored_components_test =
-        dir.file_name().unwrap().to_str().unwrap() == "react_ignored_components";
-    let is_source_path_test = dir.file_name().unwrap().to_str().unwrap() == "react_source_path";
-    let is_inline_styled_test =
-        dir.file_name().unwrap().to_str().unwrap() == "react_inline_styled_component";
-
-    let config = if is_sentry_test || is_index_test {
-        PluginConfig {
+    let filename = FileName::Custom("Button.tsx".to_string());
+    assert_eq!(extract_file_stem(&filename), Some("Button".to_string()));
+    assert_eq!(extract_dirname(&filename), None);
+}
+
+fn fixture_config(fixture_name: &str) -> PluginConfig {
+    match fixture_name {
+        "react_sentry_attrs" | "react_index_file" => PluginConfig {
             component_attr: Some("data-sentry-component".to_string()),
             element_attr: Some("data-sentry-element".to_string()),
             source_file_attr: Some("data-sentry-source-file".to_string()),
             ..Default::default()
-        }
-    } else if is_ignored_components_test {
-        PluginConfig {
+        },
+        "react_ignored_components" => PluginConfig {
             ignored_components: vec![
                 "IgnoredComponent".to_string(),
                 "AnotherIgnoredComponent".to_string(),
                 "IgnoredClassComponent".to_string(),
             ],
             ..Default::default()
-        }
-    } else if is_source_path_test {
-        PluginConfig {
+        },
+        "react_source_path" => PluginConfig {
             source_path_attr: Some("data-source-path".to_string()),
             ..Default::default()
-        }
-    } else if is_inline_styled_test {
-        PluginConfig {
+        },
+        "react_inline_styled_component" => PluginConfig {
             experimental_rewrite_emotion_styled: true,
             ..Default::default()
+        },
+        "react_component_wrappers" => PluginConfig {
+            component_wrappers: vec![
+                "memo".to_string(),
+                "forwardRef".to_string(),
+                "React.memo".to_string(),
+                "React.forwardRef".to_string(),
+                "observer".to_string(),
+            ],
+            ..Default::default()
+        },
+        "react_default_export_fn"
+        | "react_default_export_memo"
+        | "react_default_export_class"
+        | "react_default_export_wrapped_named"
+        | "react_commonjs_module_exports" => PluginConfig {
+            name_default_exports_from_file: true,
+            ..Default::default()
+        },
+        "react_component_detection_any" => PluginConfig {
+            component_detection: ComponentDetection::Any,
+            ..Default::default()
+        },
+        "react_component_detection_pattern" => PluginConfig {
+            component_detection: ComponentDetection::Pattern("^[A-Z]|Screen$".to_string()),
+            ..Default::default()
+        },
+        "react_class_custom_base_classes" => PluginConfig {
+            component_base_classes: vec![
+                "React.Component".to_string(),
+                "AppComponent".to_string(),
+                "ui.BaseComponent".to_string(),
+            ],
+            ..Default::default()
+        },
+        "react_display_name" => PluginConfig {
+            use_display_name: true,
+            ..Default::default()
+        },
+        "react_create_element" | "react_jsx_runtime" | "react_create_element_local_pragma" => {
+            PluginConfig {
+                annotate_create_element: true,
+                ..Default::default()
+            }
         }
-    } else {
-        PluginConfig::default()
-    };
+        "react_render_callbacks" => PluginConfig {
+            ignored_components: vec!["IgnoredOwner".to_string()],
+            ..Default::default()
+        },
+        "react_render_callback_owner" => PluginConfig {
+            ignored_components: vec!["IgnoredOwner".to_string()],
+            render_callback_owner: true,
+            ..Default::default()
+        },
+        "react_custom_transparent_components" => PluginConfig {
+            transparent_components: vec!["QueryClientProvider".to_string()],
+            ..Default::default()
+        },
+        "react_component_attr_host" => PluginConfig {
+            component_attr_target: ComponentAttrTarget::Host,
+            ..Default::default()
+        },
+        "react_host_element_lists" => PluginConfig {
+            extra_ignored_elements: vec!["Spacer".to_string()],
+            annotate_elements: vec!["section".to_string()],
+            ..Default::default()
+        },
+        "react_host_elements_jsx" => PluginConfig {
+            host_elements: HostElements::Jsx,
+            ..Default::default()
+        },
+        "react_native_elements" | "react_native_imported_elements" => PluginConfig {
+            native: true,
+            ..Default::default()
+        },
+        "react_native_custom_elements" => PluginConfig {
+            native: true,
+            native_elements: vec!["LinearGradient".to_string()],
+            ..Default::default()
+        },
+        "react_styled_host_elements" | "react_styled_components_host_elements" => PluginConfig {
+            experimental_rewrite_emotion_styled: true,
+            ..Default::default()
+        },
+        "react_styled_forward_ref" => PluginConfig {
+            experimental_rewrite_emotion_styled: true,
+            ignored_components: vec!["Tooltip".to_string()],
+            ..Default::default()
+        },
+        "react_styled_targets"
+        | "react_styled_default_export_host"
+        | "react_styled_forward_ref_imports" => PluginConfig {
+            experimental_rewrite_emotion_styled: true,
+            ..Default::default()
+        },
+        "react_emotion_label" => PluginConfig {
+            emotion_label: true,
+            ..Default::default()
+        },
+        "react_emotion_label_format" => PluginConfig {
+            emotion_label: true,
+            emotion_label_format: "[dirname]-[filename]--[local]".to_string(),
+            experimental_rewrite_emotion_styled: true,
+            ..Default::default()
+        },
+        "react_styled_import_sources" => PluginConfig {
+            experimental_rewrite_emotion_styled: true,
+            ..Default::default()
+        },
+        "react_custom_styled_import_sources" => PluginConfig {
+            experimental_rewrite_emotion_styled: true,
+            styled_import_sources: [(
+                "@acme/design-system".to_string(),
+                vec!["styled".to_string()],
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        },
+        _ => PluginConfig::default(),
+    }
+}
 
-    // Use custom filename for index test
-    let filename = if is_index_test {
-        FileName::Custom("react_index_file/index.jsx".to_string())
-    } else if is_source_path_test {
-        FileName::Custom(
+fn fixture_filename(fixture_name: &str) -> FileName {
+    match fixture_name {
+        "react_index_file" => FileName::Custom("react_index_file/index.jsx".to_string()),
+        "react_source_path" => FileName::Custom(
             "/mock/absolute/path/tests/fixture/react_source_path/input.jsx".to_string(),
-        )
+        ),
+        "react_default_export_fn" => FileName::Custom("src/pages/Home/index.jsx".to_string()),
+        "react_default_export_memo" => FileName::Custom("src/components/UserCard.jsx".to_string()),
+        "react_default_export_class" => FileName::Custom("src/Profile.jsx".to_string()),
+        "react_default_export_wrapped_named" => FileName::Custom("src/Toolbar.jsx".to_string()),
+        "react_commonjs_module_exports" | "react_commonjs_module_exports_unnamed" => {
+            FileName::Custom("src/Settings.jsx".to_string())
+        }
+        "react_styled_default_export_host" => FileName::Custom("src/PageGrid.jsx".to_string()),
+        "react_emotion_label_format" => FileName::Custom("src/cards/Card.styles.jsx".to_string()),
+        _ => FileName::Custom("test.jsx".to_string()),
+    }
+}
+
+#[testing::fixture("tests/fixture/react_*/input.jsx")]
+fn test(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+    let jsx = input.extension().unwrap() == "jsx";
+    let output = if jsx {
+        dir.join("output.jsx")
     } else {
-        FileName::Custom("test.jsx".to_string())
+        dir.join("output.js")
     };
 
+    let fixture_name = dir.file_name().unwrap().to_str().unwrap();
+    let config = fixture_config(fixture_name);
+    let filename = fixture_filename(fixture_name);
+
     test_fixture(
         Syntax::Es(EsSyntax {
             jsx,
             ..Default::default()
         }),
-        &|_| tr_with_config_and_filename(config.clone(), filename.clone()),
+        &|_| tr_with_config_and_filename(config.clone(), filename.clone(), false),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/react_*/input.tsx")]
+fn test_tsx(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+    let output = dir.join("output.tsx");
+
+    let fixture_name = dir.file_name().unwrap().to_str().unwrap();
+    let config = fixture_config(fixture_name);
+    let filename = fixture_filename(fixture_name);
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        &|_| tr_with_config_and_filename(config.clone(), filename.clone(), true),
         &input,
         &output,
         Default::default(),
     );
 }
 
+#[test]
+fn test_extract_component_name() {
+    use swc_plugin_component_annotate::path_utils::extract_component_name;
+
+    assert_eq!(
+        extract_component_name(&FileName::Custom("src/components/UserCard.tsx".to_string())),
+        Some("UserCard".to_string())
+    );
+
+    // Index files are named after their directory
+    assert_eq!(
+        extract_component_name(&FileName::Custom("src/pages/Home/index.tsx".to_string())),
+        Some("Home".to_string())
+    );
+    assert_eq!(
+        extract_component_name(&FileName::Custom(
+            "src\\pages\\Settings\\index.jsx".to_string()
+        )),
+        Some("Settings".to_string())
+    );
+
+    // Only the first extension-like segment is kept
+    assert_eq!(
+        extract_component_name(&FileName::Custom("Button.stories.tsx".to_string())),
+        Some("Button".to_string())
+    );
+
+    // A bare index file has nothing to name the component after
+    assert_eq!(
+        extract_component_name(&FileName::Custom("index.tsx".to_string())),
+        None
+    );
+}
+
 #[test]
 fn test_ignored_components_config() {
     use swc_plugin_component_annotate::config::PluginConfig;
@@ -273,3 +477,231 @@ fn test_plugin_config_parsing() {
     assert_eq!(native_config.source_file_attr_name(), "dataSourceFile");
     assert_eq!(native_config.source_path_attr_name(), "dataSourcePath");
 }
+
+#[test]
+fn test_component_wrappers_config() {
+    use swc_plugin_component_annotate::config::PluginConfig;
+
+    // Defaults cover React's own wrappers
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(
+        default_config.component_wrappers,
+        vec!["memo", "forwardRef", "React.memo", "React.forwardRef"]
+    );
+    assert_eq!(
+        PluginConfig::default().component_wrappers,
+        default_config.component_wrappers
+    );
+
+    // A custom list replaces the defaults
+    let config_json = r#"{
+        "component-wrappers": ["observer", "React.memo"]
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(
+        parsed_config.component_wrappers,
+        vec!["observer", "React.memo"]
+    );
+}
+
+#[test]
+fn test_component_base_classes_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(
+        default_config.component_base_classes,
+        vec![
+            "Component",
+            "PureComponent",
+            "React.Component",
+            "React.PureComponent"
+        ]
+    );
+
+    let config_json = r#"{
+        "component-base-classes": ["React.Component", "AppComponent"]
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(
+        parsed_config.component_base_classes,
+        vec!["React.Component", "AppComponent"]
+    );
+}
+
+#[test]
+fn test_create_element_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert!(!default_config.annotate_create_element);
+    assert!(default_config
+        .create_element_pragmas
+        .contains(&"React.createElement".to_string()));
+    assert!(default_config
+        .create_element_pragmas
+        .contains(&"_jsx".to_string()));
+
+    let config_json = r#"{
+        "annotate-create-element": true,
+        "create-element-pragmas": ["createVNode"]
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert!(parsed_config.annotate_create_element);
+    assert_eq!(parsed_config.create_element_pragmas, vec!["createVNode"]);
+}
+
+#[test]
+fn test_render_callbacks_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(
+        default_config.render_callbacks,
+        vec![
+            "useMemo",
+            "useCallback",
+            "React.useMemo",
+            "React.useCallback"
+        ]
+    );
+    assert!(!default_config.render_callback_owner);
+
+    let config_json = r#"{
+        "render-callbacks": ["useMemo", "useDeferredRender"],
+        "render-callback-owner": true
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(
+        parsed_config.render_callbacks,
+        vec!["useMemo", "useDeferredRender"]
+    );
+    assert!(parsed_config.render_callback_owner);
+}
+
+#[test]
+fn test_transparent_components_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert!(default_config
+        .transparent_components
+        .contains(&"Suspense".to_string()));
+    assert!(default_config
+        .transparent_components
+        .contains(&"*.Provider".to_string()));
+
+    let config_json = r#"{
+        "transparent-components": ["QueryClientProvider", "*.Provider"]
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(
+        parsed_config.transparent_components,
+        vec!["QueryClientProvider", "*.Provider"]
+    );
+}
+
+#[test]
+fn test_component_attr_target_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(
+        default_config.component_attr_target,
+        ComponentAttrTarget::Root
+    );
+
+    let config_json = r#"{ "component-attr-target": "host" }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(
+        parsed_config.component_attr_target,
+        ComponentAttrTarget::Host
+    );
+}
+
+#[test]
+fn test_component_detection_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(
+        default_config.component_detection,
+        ComponentDetection::PascalCase
+    );
+
+    let parse = |value: &str| {
+        let config_json = format!(r#"{{ "component-detection": "{value}" }}"#);
+        serde_json::from_str::<PluginConfig>(&config_json)
+            .unwrap()
+            .component_detection
+    };
+    assert_eq!(parse("pascal-case"), ComponentDetection::PascalCase);
+    assert_eq!(parse("any"), ComponentDetection::Any);
+    assert_eq!(
+        parse("^[A-Z]|Screen$"),
+        ComponentDetection::Pattern("^[A-Z]|Screen$".to_string())
+    );
+}
+
+#[test]
+fn test_host_elements_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(default_config.host_elements, HostElements::List);
+    assert!(default_config.extra_ignored_elements.is_empty());
+    assert!(default_config.annotate_elements.is_empty());
+
+    let config_json = r#"{
+        "host-elements": "jsx",
+        "extra-ignored-elements": ["Spacer"],
+        "annotate-elements": ["section"]
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(parsed_config.host_elements, HostElements::Jsx);
+    assert_eq!(parsed_config.extra_ignored_elements, vec!["Spacer"]);
+    assert_eq!(parsed_config.annotate_elements, vec!["section"]);
+}
+
+#[test]
+fn test_native_elements_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert!(default_config.native_elements.is_empty());
+
+    let config_json = r#"{
+        "native": true,
+        "native-elements": ["LinearGradient", "BlurView"]
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(
+        parsed_config.native_elements,
+        vec!["LinearGradient", "BlurView"]
+    );
+}
+
+#[test]
+fn test_styled_import_sources_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(
+        default_config.styled_import_sources["@emotion/styled"],
+        vec!["default", "styled"]
+    );
+    assert_eq!(
+        default_config.styled_import_sources["@mui/material/styles"],
+        vec!["styled"]
+    );
+    assert!(default_config
+        .styled_import_sources
+        .contains_key("styled-components"));
+
+    let config_json = r#"{
+        "styled-import-sources": { "@acme/design-system": ["styled", "default"] }
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert_eq!(parsed_config.styled_import_sources.len(), 1);
+    assert_eq!(
+        parsed_config.styled_import_sources["@acme/design-system"],
+        vec!["styled", "default"]
+    );
+}
+
+#[test]
+fn test_emotion_label_config() {
+    let default_config: PluginConfig = serde_json::from_str("{}").unwrap();
+    assert!(!default_config.emotion_label);
+    assert_eq!(default_config.emotion_label_format, "[local]");
+
+    let config_json = r#"{
+        "emotion-label": true,
+        "emotion-label-format": "[filename]--[local]"
+    }"#;
+    let parsed_config: PluginConfig = serde_json::from_str(config_json).unwrap();
+    assert!(parsed_config.emotion_label);
+    assert_eq!(parsed_config.emotion_label_format, "[filename]--[local]");
+}